@@ -1,12 +1,25 @@
+use criterion_charts_tsx::budgets::Budgets;
 use criterion_charts_tsx::ccharts::CriterionChartsTsx;
+use criterion_charts_tsx::junit::JUnitReport;
+use criterion_charts_tsx::meanband::MeanBand;
+use criterion_charts_tsx::pdfmode::PdfMode;
+use criterion_charts_tsx::rawdata::CriterionData;
+use criterion_charts_tsx::regression::RegressionCheck;
+use criterion_charts_tsx::timeunit::TimeUnit;
 
-use clap::{App, Arg};
+use clap::{App, Arg, ArgMatches, SubCommand};
 use glob::glob;
 use std::error::Error;
 use std::fmt;
 use std::path::Path;
 use std::path::PathBuf;
 
+/// Exit status when the options are invalid or the files cannot be read or
+/// written
+const EXIT_ERROR: i32 = 1;
+/// Exit status when a budget is exceeded or a function regressed
+const EXIT_CHECK_FAILED: i32 = 2;
+
 #[derive(Debug, Clone)]
 struct TsxGenError {
     msg: String,
@@ -59,13 +72,299 @@ fn main() {
                 .takes_value(true)
                 .help("Glob that specifies the search if the 'dir' option is used."),
         )
+        .arg(
+            Arg::with_name("pdf-mode")
+                .long("pdf-mode")
+                .takes_value(true)
+                .help("How the PDF chart shows the distribution: kde (default), fd, sturges or a fixed number of histogram bins."),
+        )
+        .arg(
+            Arg::with_name("group-pdf-mode")
+                .long("group-pdf-mode")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .help("Overrides the --pdf-mode for a single group. Specified as <group>=<mode>. Can be repeated."),
+        )
+        .arg(
+            Arg::with_name("mean-band")
+                .long("mean-band")
+                .takes_value(true)
+                .help("Emits the band drawn around the mean in the mean time chart: ci, sd or <k>sd e.g. 2sd. No band data is emitted without it."),
+        )
+        .arg(
+            Arg::with_name("relative-to")
+                .long("relative-to")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .help("Baseline function that the other functions in its group are measured against. Can be repeated; the first one found in a group is used."),
+        )
+        .arg(
+            Arg::with_name("score")
+                .long("score")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .help("Prints and exports the geometric mean of the time ratio of two functions across all groups. Specified as <fnA>,<fnB>. Can be repeated."),
+        )
+        .arg(
+            Arg::with_name("baseline-glob")
+                .long("baseline-glob")
+                .takes_value(true)
+                .help("Glob of the raw.csv files of a baseline run in the 'dir' e.g. **/base/raw.csv. The suite score of the loaded run against it is printed and exported."),
+        )
+        .arg(
+            Arg::with_name("budgets")
+                .long("budgets")
+                .takes_value(true)
+                .help("Path to a TOML file of budgets per group and function e.g. vec-push = \"p95 < 120 ns\". Prints a report and exits with status 2 if any budget is exceeded."),
+        )
+        .arg(
+            Arg::with_name("markdown")
+                .long("markdown")
+                .takes_value(true)
+                .help("Path to a Markdown report to write with a table of stats per group. Includes the change of each function if --baseline-glob is specified."),
+        )
+        .arg(
+            Arg::with_name("summary-json")
+                .long("summary-json")
+                .takes_value(true)
+                .help("Path to a JSON file to write with the computed statistics of every group and function."),
+        )
+        .arg(
+            Arg::with_name("summary-csv")
+                .long("summary-csv")
+                .takes_value(true)
+                .help("Path to a CSV file to write with the computed statistics of every group and function."),
+        )
+        .arg(
+            Arg::with_name("junit")
+                .long("junit")
+                .takes_value(true)
+                .help("Path to a JUnit XML report to write with a test case for every budget and, if --baseline-glob is specified, for every function checked for regressions."),
+        )
+        .arg(
+            Arg::with_name("svg")
+                .long("svg")
+                .takes_value(true)
+                .help("Path to a directory where the MT, LR and PDF charts of every group are written as standalone SVG files."),
+        )
+        .arg(
+            Arg::with_name("vega-lite")
+                .long("vega-lite")
+                .takes_value(true)
+                .help("Path to a directory where the MT, LR and PDF charts of every group are written as Vega-Lite specifications with inline data."),
+        )
+        .arg(
+            Arg::with_name("plotly")
+                .long("plotly")
+                .takes_value(true)
+                .help("Path to a directory where the MT, LR and PDF charts of every group are written as Plotly figures."),
+        )
+        .arg(
+            Arg::with_name("gnuplot")
+                .long("gnuplot")
+                .takes_value(true)
+                .help("Path to a directory where the MT, LR and PDF charts of every group are written as .dat files with gnuplot scripts that render them to PDF."),
+        )
+        .arg(
+            Arg::with_name("pgfplots")
+                .long("pgfplots")
+                .takes_value(true)
+                .help("Path to a directory where the MT, LR and PDF charts of every group are written as .dat files with pgfplots .tex snippets."),
+        )
+        .arg(
+            Arg::with_name("time-unit")
+                .long("time-unit")
+                .takes_value(true)
+                .help("Time unit of the SVG, Vega-Lite, Plotly, gnuplot and pgfplots charts: s, ms, us, ns or ps. Defaults to the time unit of the data."),
+        )
+        .arg(
+            Arg::with_name("series-color")
+                .long("series-color")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .help("Color of a function in the SVG, Vega-Lite, Plotly, gnuplot and pgfplots charts. Specified as <function>=<color> e.g. vec=#1f77b4. Can be repeated."),
+        )
+        .subcommand(
+            SubCommand::with_name("check")
+                .about("Compares a run against a baseline run and exits with status 2 if any function regressed.")
+                .arg(
+                    Arg::with_name("dir")
+                        .short("d")
+                        .long("dir")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Path to a directory containing raw.csv files."),
+                )
+                .arg(
+                    Arg::with_name("glob")
+                        .short("g")
+                        .long("glob")
+                        .takes_value(true)
+                        .help("Glob of the raw.csv files of the run to check. Defaults to **/new/raw.csv"),
+                )
+                .arg(
+                    Arg::with_name("baseline-dir")
+                        .long("baseline-dir")
+                        .takes_value(true)
+                        .help("Path to a directory containing the raw.csv files of the baseline run. Defaults to the 'dir'."),
+                )
+                .arg(
+                    Arg::with_name("baseline-glob")
+                        .long("baseline-glob")
+                        .takes_value(true)
+                        .default_value("**/base/raw.csv")
+                        .help("Glob of the raw.csv files of the baseline run."),
+                )
+                .arg(
+                    Arg::with_name("threshold")
+                        .long("threshold")
+                        .takes_value(true)
+                        .help("Relative change in the mean that is reported e.g. 0.05 or 5% (default)."),
+                )
+                .arg(
+                    Arg::with_name("group-threshold")
+                        .long("group-threshold")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .help("Overrides the --threshold for a single group. Specified as <group>=<threshold>. Can be repeated."),
+                )
+                .arg(
+                    Arg::with_name("junit")
+                        .long("junit")
+                        .takes_value(true)
+                        .help("Path to a JUnit XML report to write with a test case for every function."),
+                )
+                .arg(
+                    Arg::with_name("significance")
+                        .long("significance")
+                        .takes_value(true)
+                        .help("p-value of Welch's t-test below which a change is significant. Defaults to 0.05."),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("report")
+                .about("Writes a single HTML file with the stats and charts of every group that can be viewed offline.")
+                .arg(
+                    Arg::with_name("dir")
+                        .short("d")
+                        .long("dir")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Path to a directory containing raw.csv files."),
+                )
+                .arg(
+                    Arg::with_name("glob")
+                        .short("g")
+                        .long("glob")
+                        .takes_value(true)
+                        .help("Glob of the raw.csv files of the run. Defaults to **/new/raw.csv"),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .short("o")
+                        .long("output")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Path to the HTML file to write."),
+                )
+                .arg(
+                    Arg::with_name("title")
+                        .long("title")
+                        .takes_value(true)
+                        .default_value("Benchmark Results")
+                        .help("Title of the report."),
+                )
+                .arg(
+                    Arg::with_name("baseline-glob")
+                        .long("baseline-glob")
+                        .takes_value(true)
+                        .help("Glob of the raw.csv files of a baseline run in the 'dir' e.g. **/base/raw.csv. The change of every function is included in the report."),
+                )
+                .arg(
+                    Arg::with_name("pdf-mode")
+                        .long("pdf-mode")
+                        .takes_value(true)
+                        .help("How the PDF chart shows the distribution: kde (default), fd, sturges or a fixed number of histogram bins."),
+                )
+                .arg(
+                    Arg::with_name("time-unit")
+                        .long("time-unit")
+                        .takes_value(true)
+                        .help("Time unit of the charts: s, ms, us, ns or ps. Defaults to the time unit of the data."),
+                )
+                .arg(
+                    Arg::with_name("series-color")
+                        .long("series-color")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .help("Color of a function in the charts. Specified as <function>=<color>. Can be repeated."),
+                ),
+        )
         .get_matches();
 
+    if let Some(report_args) = cmd_args.subcommand_matches("report") {
+        if let Err(e) = report(report_args) {
+            println!("Error: {}", e);
+            std::process::exit(EXIT_ERROR);
+        }
+        return;
+    }
+
+    if let Some(check_args) = cmd_args.subcommand_matches("check") {
+        match check(check_args) {
+            Ok(true) => std::process::exit(EXIT_CHECK_FAILED),
+            Ok(false) => {}
+            Err(e) => {
+                println!("Error: {}", e);
+                std::process::exit(EXIT_ERROR);
+            }
+        }
+        return;
+    }
+
     let raw_file = cmd_args.value_of("file");
     let dir_name = cmd_args.value_of("dir");
 
     let output_dir = cmd_args.value_of("output");
     let file_glob = cmd_args.value_of("glob");
+    let baseline_glob = cmd_args.value_of("baseline-glob");
+    let generate_options = GenerateOptions {
+        pdf_mode: cmd_args.value_of("pdf-mode"),
+        group_pdf_modes: cmd_args
+            .values_of("group-pdf-mode")
+            .map(|values| values.collect())
+            .unwrap_or_default(),
+        mean_band: cmd_args.value_of("mean-band"),
+        relative_to: cmd_args
+            .values_of("relative-to")
+            .map(|values| values.collect())
+            .unwrap_or_default(),
+        scores: cmd_args
+            .values_of("score")
+            .map(|values| values.collect())
+            .unwrap_or_default(),
+        budgets: cmd_args.value_of("budgets"),
+        markdown: cmd_args.value_of("markdown"),
+        summary_json: cmd_args.value_of("summary-json"),
+        summary_csv: cmd_args.value_of("summary-csv"),
+        junit: cmd_args.value_of("junit"),
+        svg: cmd_args.value_of("svg"),
+        vega_lite: cmd_args.value_of("vega-lite"),
+        plotly: cmd_args.value_of("plotly"),
+        gnuplot: cmd_args.value_of("gnuplot"),
+        pgfplots: cmd_args.value_of("pgfplots"),
+        time_unit: cmd_args.value_of("time-unit"),
+        series_colors: cmd_args
+            .values_of("series-color")
+            .map(|values| values.collect())
+            .unwrap_or_default(),
+    };
 
     if raw_file.is_none() && dir_name.is_none() {
         println!("Error: Either the --file or the --dir options must be specified");
@@ -76,29 +375,130 @@ fn main() {
         println!("Error: Missing required option --output");
         return;
     }
-    match generate_tsx(raw_file, dir_name, output_dir, file_glob) {
-        Ok(()) => {}
+    match generate_tsx(
+        raw_file,
+        dir_name,
+        output_dir,
+        file_glob,
+        baseline_glob,
+        &generate_options,
+    ) {
+        Ok(true) => {}
+        Ok(false) => std::process::exit(EXIT_CHECK_FAILED),
         Err(e) => {
             println!("Error: {}", e);
-            return;
+            std::process::exit(EXIT_ERROR);
+        }
+    }
+}
+
+/// Options of the tsx generation: what is rendered in the generated charts,
+/// the budgets to check, and the reports and static charts written with them
+#[derive(Default)]
+struct GenerateOptions<'a> {
+    pdf_mode: Option<&'a str>,
+    group_pdf_modes: Vec<&'a str>,
+    mean_band: Option<&'a str>,
+    relative_to: Vec<&'a str>,
+    scores: Vec<&'a str>,
+    budgets: Option<&'a str>,
+    markdown: Option<&'a str>,
+    summary_json: Option<&'a str>,
+    summary_csv: Option<&'a str>,
+    junit: Option<&'a str>,
+    svg: Option<&'a str>,
+    vega_lite: Option<&'a str>,
+    plotly: Option<&'a str>,
+    gnuplot: Option<&'a str>,
+    pgfplots: Option<&'a str>,
+    time_unit: Option<&'a str>,
+    series_colors: Vec<&'a str>,
+}
+
+impl GenerateOptions<'_> {
+    fn apply(&self, ccharts_tsx: &mut CriterionChartsTsx) -> Result<(), Box<dyn Error>> {
+        ccharts_tsx.set_relative_to(&self.relative_to);
+
+        let mut scores = Vec::<(&str, &str)>::new();
+        for score in &self.scores {
+            match score.split_once(',') {
+                Some(pair) => scores.push(pair),
+                None => {
+                    return Err(TsxGenError::new(format!(
+                        "Invalid --score {}. Expected <fnA>,<fnB>",
+                        score
+                    ))
+                    .into())
+                }
+            }
+        }
+        ccharts_tsx.set_scores(&scores);
+
+        if let Some(budgets) = self.budgets {
+            ccharts_tsx.set_budgets(Budgets::load(Path::new(budgets))?);
+        }
+
+        if let Some(mean_band) = self.mean_band {
+            ccharts_tsx.set_mean_band(mean_band.parse::<MeanBand>()?);
+        }
+
+        if let Some(pdf_mode) = self.pdf_mode {
+            ccharts_tsx.set_pdf_mode(pdf_mode.parse::<PdfMode>()?);
+        }
+
+        if let Some(time_unit) = self.time_unit {
+            ccharts_tsx.set_time_unit(time_unit.parse::<TimeUnit>()?);
+        }
+
+        for series_color in &self.series_colors {
+            match series_color.split_once('=') {
+                Some((fn_name, color)) => ccharts_tsx.set_series_color(fn_name, color),
+                None => {
+                    return Err(TsxGenError::new(format!(
+                        "Invalid --series-color {}. Expected <function>=<color>",
+                        series_color
+                    ))
+                    .into())
+                }
+            }
         }
+
+        for group_pdf_mode in &self.group_pdf_modes {
+            match group_pdf_mode.split_once('=') {
+                Some((group, pdf_mode)) => {
+                    ccharts_tsx.set_group_pdf_mode(group, pdf_mode.parse::<PdfMode>()?)
+                }
+                None => {
+                    return Err(TsxGenError::new(format!(
+                        "Invalid --group-pdf-mode {}. Expected <group>=<mode>",
+                        group_pdf_mode
+                    ))
+                    .into())
+                }
+            }
+        }
+        Ok(())
     }
 }
 
+/// Generates the tsx files. Returns false if any budget was exceeded.
 fn generate_tsx(
     raw_file: Option<&str>,
     dir_name: Option<&str>,
     output_dir: Option<&str>,
     file_glob: Option<&str>,
-) -> Result<(), Box<dyn Error>> {
+    baseline_glob: Option<&str>,
+    generate_options: &GenerateOptions,
+) -> Result<bool, Box<dyn Error>> {
     let mut ccharts_tsx = CriterionChartsTsx::new();
+    generate_options.apply(&mut ccharts_tsx)?;
 
     match raw_file {
         None => {
             let filepaths = find_files(dir_name.unwrap(), file_glob)?;
-            if filepaths.len() == 0 {
+            if filepaths.is_empty() {
                 println!("    Found 0 raw.csv files to load. Nothing to do");
-                return Ok(());
+                return Ok(true);
             }
             println!("    Loading {} raw.csv files", filepaths.len());
             for filepath in &filepaths {
@@ -118,7 +518,172 @@ fn generate_tsx(
         }
     }
 
-    ccharts_tsx.generate_tsx(Path::new(output_dir.unwrap()))
+    if let Some(baseline_glob) = baseline_glob {
+        let dir_name = dir_name.ok_or_else(|| {
+            TsxGenError::new("The --baseline-glob option requires the --dir option".to_string())
+        })?;
+        for filepath in &find_files(dir_name, Some(baseline_glob))? {
+            println!(
+                "    Loading baseline data from file: {}",
+                filepath.display()
+            );
+            ccharts_tsx.load_baseline(filepath)?;
+        }
+    }
+
+    for score in ccharts_tsx.suite_scores().scores() {
+        println!("    Suite score {}", score);
+    }
+
+    ccharts_tsx.generate_tsx(Path::new(output_dir.unwrap()))?;
+
+    if let Some(svg) = generate_options.svg {
+        ccharts_tsx.generate_svg(Path::new(svg))?;
+    }
+
+    if let Some(vega_lite) = generate_options.vega_lite {
+        ccharts_tsx.generate_vega_lite(Path::new(vega_lite))?;
+    }
+
+    if let Some(plotly) = generate_options.plotly {
+        ccharts_tsx.generate_plotly(Path::new(plotly))?;
+    }
+
+    if let Some(gnuplot) = generate_options.gnuplot {
+        ccharts_tsx.generate_gnuplot(Path::new(gnuplot))?;
+    }
+
+    if let Some(pgfplots) = generate_options.pgfplots {
+        ccharts_tsx.generate_pgfplots(Path::new(pgfplots))?;
+    }
+
+    if let Some(markdown) = generate_options.markdown {
+        ccharts_tsx.generate_markdown(Path::new(markdown))?;
+    }
+
+    if let Some(summary_json) = generate_options.summary_json {
+        ccharts_tsx.generate_summary_json(Path::new(summary_json))?;
+    }
+
+    if let Some(summary_csv) = generate_options.summary_csv {
+        ccharts_tsx.generate_summary_csv(Path::new(summary_csv))?;
+    }
+
+    if let Some(junit) = generate_options.junit {
+        ccharts_tsx.generate_junit(Path::new(junit))?;
+    }
+
+    if generate_options.budgets.is_some() {
+        let budget_report = ccharts_tsx.budget_report();
+        println!("{}", budget_report);
+        return Ok(!budget_report.has_failures());
+    }
+    Ok(true)
+}
+
+/// Writes the HTML report of a run
+fn report(report_args: &ArgMatches) -> Result<(), Box<dyn Error>> {
+    let generate_options = GenerateOptions {
+        pdf_mode: report_args.value_of("pdf-mode"),
+        time_unit: report_args.value_of("time-unit"),
+        series_colors: report_args
+            .values_of("series-color")
+            .map(|values| values.collect())
+            .unwrap_or_default(),
+        ..Default::default()
+    };
+    let mut ccharts_tsx = CriterionChartsTsx::new();
+    generate_options.apply(&mut ccharts_tsx)?;
+
+    let dir_name = report_args.value_of("dir").unwrap();
+    for filepath in &find_files(dir_name, report_args.value_of("glob"))? {
+        println!("    Loading raw data from file: {}", filepath.display());
+        ccharts_tsx.load(filepath)?;
+    }
+    if let Some(baseline_glob) = report_args.value_of("baseline-glob") {
+        for filepath in &find_files(dir_name, Some(baseline_glob))? {
+            println!(
+                "    Loading baseline data from file: {}",
+                filepath.display()
+            );
+            ccharts_tsx.load_baseline(filepath)?;
+        }
+    }
+
+    ccharts_tsx.generate_html(
+        Path::new(report_args.value_of("output").unwrap()),
+        report_args.value_of("title").unwrap(),
+    )
+}
+
+/// Runs the regression check. Returns true if any function regressed.
+fn check(check_args: &ArgMatches) -> Result<bool, Box<dyn Error>> {
+    let mut regression_check = RegressionCheck::new();
+    if let Some(threshold) = check_args.value_of("threshold") {
+        regression_check.set_threshold(parse_threshold(threshold)?);
+    }
+    for group_threshold in check_args.values_of("group-threshold").unwrap_or_default() {
+        match group_threshold.split_once('=') {
+            Some((group, threshold)) => {
+                regression_check.set_group_threshold(group, parse_threshold(threshold)?)
+            }
+            None => {
+                return Err(TsxGenError::new(format!(
+                    "Invalid --group-threshold {}. Expected <group>=<threshold>",
+                    group_threshold
+                ))
+                .into())
+            }
+        }
+    }
+    if let Some(significance) = check_args.value_of("significance") {
+        regression_check.set_significance(significance.parse::<f64>()?);
+    }
+
+    let dir_name = check_args.value_of("dir").unwrap();
+    let current = load_data(dir_name, check_args.value_of("glob"))?;
+    let baseline = load_data(
+        check_args.value_of("baseline-dir").unwrap_or(dir_name),
+        check_args.value_of("baseline-glob"),
+    )?;
+
+    let report = regression_check.check(&current, &baseline);
+    if report.results().is_empty() {
+        return Err(TsxGenError::new(
+            "The run and the baseline run have no functions in common".to_string(),
+        )
+        .into());
+    }
+    println!("{}", report);
+
+    if let Some(junit) = check_args.value_of("junit") {
+        println!("Generating JUnit report: {}", junit);
+        let mut junit_report = JUnitReport::new("benchmarks");
+        junit_report.add_regression_report(&report);
+        junit_report.write(Path::new(junit))?;
+    }
+    Ok(report.has_regressions())
+}
+
+/// Parses a threshold given as a fraction e.g. 0.05 or a percentage e.g. 5%
+fn parse_threshold(threshold: &str) -> Result<f64, Box<dyn Error>> {
+    match threshold.strip_suffix('%') {
+        Some(percent) => Ok(percent.trim().parse::<f64>()? / 100.0),
+        None => Ok(threshold.parse::<f64>()?),
+    }
+}
+
+fn load_data(dir: &str, file_glob: Option<&str>) -> Result<CriterionData, Box<dyn Error>> {
+    let mut cdata = CriterionData::new();
+    for filepath in &find_files(dir, file_glob)? {
+        match filepath.to_str() {
+            Some(filepath) => cdata.load(filepath)?,
+            None => {
+                return Err(TsxGenError::new(format!("Invalid path {}", filepath.display())).into())
+            }
+        }
+    }
+    Ok(cdata)
 }
 
 fn find_files(dir: &str, file_glob: Option<&str>) -> Result<Vec<PathBuf>, Box<dyn Error>> {
@@ -131,16 +696,13 @@ fn find_files(dir: &str, file_glob: Option<&str>) -> Result<Vec<PathBuf>, Box<dy
         );
     }
 
-    let glob_path;
-    match file_glob {
+    let glob_path = match file_glob {
         None => {
             println!("    --glob option not specified. Defaulting to **/new/raw.csv");
-            glob_path = format!("{}/**/new/raw.csv", dir_path.display());
+            format!("{}/**/new/raw.csv", dir_path.display())
         }
-        Some(file_glob) => {
-            glob_path = format!("{}/{}", dir_path.display(), file_glob);
-        }
-    }
+        Some(file_glob) => format!("{}/{}", dir_path.display(), file_glob),
+    };
 
     println!("    Searching for raw files matching glob {}", glob_path);
 
@@ -0,0 +1,458 @@
+use crate::infomap::BudgetMap;
+use crate::rawdata::CriterionData;
+use crate::stats::{percentile, sorted, UvAnalysis};
+use crate::timeunit::TimeUnit;
+use crate::FnName;
+use crate::GroupName;
+use crate::Measure;
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The key that applies a budget to every function of a group
+const ALL_FUNCTIONS: &str = "*";
+
+/// The statistic of the mean time per iteration that a budget limits
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Statistic {
+    Mean,
+    Median,
+    Min,
+    Max,
+    /// The percentile e.g. 95.0 for p95
+    Percentile(f64),
+}
+
+/// An absolute limit on a statistic of the mean time per iteration of a
+/// function e.g. "p95 < 120 ns"
+#[derive(Debug, PartialEq, Clone)]
+pub struct Budget {
+    statistic: Statistic,
+    inclusive: bool,
+    limit: f64,
+    unit: TimeUnit,
+}
+
+/// The budgets of every group and function, read from a TOML file with one
+/// table per group and one key per function:
+///
+/// ```toml
+/// [push]
+/// vec-push = ["p95 < 120 ns", "mean < 2 µs"]
+/// "*" = "max < 1 ms"
+/// ```
+///
+/// The `"*"` key applies to every function of the group.
+#[derive(Debug, Default)]
+pub struct Budgets {
+    budgets: BTreeMap<GroupName, BTreeMap<FnName, Vec<Budget>>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ParseBudgetError(String);
+
+/// The result of evaluating one budget of a function
+pub struct BudgetResult {
+    group: GroupName,
+    function: FnName,
+    budget: Budget,
+    measured: Measure,
+}
+
+/// The result of evaluating every budget against the loaded data
+pub struct BudgetReport {
+    results: Vec<BudgetResult>,
+}
+
+impl Error for ParseBudgetError {}
+
+impl Display for ParseBudgetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid budget: {}", self.0)
+    }
+}
+
+impl Display for Statistic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Statistic::Mean => write!(f, "mean"),
+            Statistic::Median => write!(f, "median"),
+            Statistic::Min => write!(f, "min"),
+            Statistic::Max => write!(f, "max"),
+            Statistic::Percentile(p) => write!(f, "p{}", p),
+        }
+    }
+}
+
+impl FromStr for Statistic {
+    type Err = ParseBudgetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "mean" => Ok(Self::Mean),
+            "median" => Ok(Self::Median),
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            stat => match stat.strip_prefix('p').map(|p| p.parse::<f64>()) {
+                Some(Ok(p)) if (0.0..=100.0).contains(&p) => Ok(Self::Percentile(p)),
+                _ => Err(ParseBudgetError(format!("unknown statistic {}", s))),
+            },
+        }
+    }
+}
+
+impl Statistic {
+    /// Evaluates this statistic of the sample
+    pub fn eval(&self, uva: &UvAnalysis) -> f64 {
+        let sample = sorted(uva.sample());
+        match self {
+            Statistic::Mean => uva.mean(),
+            Statistic::Median => percentile(&sample, 0.5),
+            Statistic::Min => percentile(&sample, 0.0),
+            Statistic::Max => percentile(&sample, 1.0),
+            Statistic::Percentile(p) => percentile(&sample, p / 100.0),
+        }
+    }
+}
+
+impl Display for Budget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.statistic,
+            self.operator(),
+            self.limit,
+            self.unit
+        )
+    }
+}
+
+impl FromStr for Budget {
+    type Err = ParseBudgetError;
+
+    /// Parses a budget of the form `<statistic> <|<= <limit> <unit>`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseBudgetError(format!("{} (expected e.g. p95 < 120 ns)", s));
+        let (statistic, limit) = s.split_once('<').ok_or_else(invalid)?;
+        let (inclusive, limit) = match limit.strip_prefix('=') {
+            Some(limit) => (true, limit.trim()),
+            None => (false, limit.trim()),
+        };
+        let value = limit.trim_end_matches(|c: char| c.is_alphabetic());
+        let unit = limit[value.len()..]
+            .parse::<TimeUnit>()
+            .map_err(|e| ParseBudgetError(e.to_string()))?;
+        Ok(Budget {
+            statistic: statistic.trim().parse::<Statistic>()?,
+            inclusive,
+            limit: value.trim().parse::<f64>().map_err(|_| invalid())?,
+            unit,
+        })
+    }
+}
+
+impl Budget {
+    pub fn statistic(&self) -> Statistic {
+        self.statistic
+    }
+
+    /// The unit that the limit is specified in
+    pub fn unit(&self) -> TimeUnit {
+        self.unit
+    }
+
+    pub fn operator(&self) -> &str {
+        if self.inclusive {
+            "<="
+        } else {
+            "<"
+        }
+    }
+
+    /// The limit in nanoseconds
+    pub fn limit_nanos(&self) -> f64 {
+        self.unit.to_nanos(self.limit)
+    }
+
+    /// True if a measured value in nanoseconds is within the budget
+    pub fn passes(&self, measured: Measure) -> bool {
+        if self.inclusive {
+            measured <= self.limit_nanos()
+        } else {
+            measured < self.limit_nanos()
+        }
+    }
+}
+
+impl Budgets {
+    pub fn new() -> Budgets {
+        Budgets {
+            budgets: BTreeMap::<GroupName, BTreeMap<FnName, Vec<Budget>>>::new(),
+        }
+    }
+
+    pub fn load(file_path: &Path) -> Result<Budgets, Box<dyn Error>> {
+        Ok(fs::read_to_string(file_path)?.parse::<Budgets>()?)
+    }
+
+    pub fn push(&mut self, group: &str, function: &str, budget: Budget) {
+        self.budgets
+            .entry(group.to_ascii_lowercase())
+            .or_default()
+            .entry(function.to_ascii_lowercase())
+            .or_default()
+            .push(budget);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.budgets.is_empty()
+    }
+
+    /// The budgets of a function including the budgets of its whole group
+    pub fn get(&self, group: &str, function: &str) -> Vec<&Budget> {
+        let fn_map = match self.budgets.get(&group.to_ascii_lowercase()) {
+            Some(fn_map) => fn_map,
+            None => return Vec::new(),
+        };
+        [function.to_ascii_lowercase().as_str(), ALL_FUNCTIONS]
+            .iter()
+            .filter_map(|key| fn_map.get(*key))
+            .flatten()
+            .collect()
+    }
+
+    /// Evaluates the budgets of every function in the loaded data
+    pub fn evaluate(&self, cdata: &CriterionData) -> BudgetReport {
+        let mut results = Vec::<BudgetResult>::new();
+        for (group, uva) in cdata.uv_analyses_nanos() {
+            for budget in self.get(group, uva.fn_name()) {
+                results.push(BudgetResult {
+                    group: group.to_string(),
+                    function: uva.fn_name().to_string(),
+                    budget: budget.clone(),
+                    measured: budget.statistic().eval(&uva),
+                });
+            }
+        }
+        BudgetReport { results }
+    }
+
+    /// The budgets of every function in the loaded data of a group, or of
+    /// every group if `group_name` is None
+    pub fn budget_map(&self, cdata: &CriterionData, group_name: Option<&str>) -> BudgetMap {
+        let groups = match group_name {
+            Some(group) => vec![group.to_string()],
+            None => cdata.groups(),
+        };
+        let mut budget_map = BudgetMap::new();
+        for group in &groups {
+            if let Some(fn_data) = cdata.get(group) {
+                for fn_name in fn_data.functions() {
+                    let budgets = self.get(group, fn_name);
+                    if !budgets.is_empty() {
+                        budget_map.push(group, fn_name, budgets.into_iter().cloned().collect());
+                    }
+                }
+            }
+        }
+        budget_map
+    }
+}
+
+impl FromStr for Budgets {
+    type Err = ParseBudgetError;
+
+    /// Parses a TOML budgets file. Every top level key must be a `[group]`
+    /// table and every key of a group a budget string or an array of budget
+    /// strings.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s
+            .parse::<toml::Value>()
+            .map_err(|e| ParseBudgetError(e.to_string()))?;
+        let mut budgets = Budgets::new();
+        let groups = value
+            .as_table()
+            .ok_or_else(|| ParseBudgetError("expected a table of groups".to_string()))?;
+        for (group, fn_map) in groups {
+            let fn_map = fn_map
+                .as_table()
+                .ok_or_else(|| ParseBudgetError(format!("{} is not a [group] table", group)))?;
+            for (function, value) in fn_map {
+                let invalid = || {
+                    ParseBudgetError(format!(
+                        "{}.{}: expected a budget string or an array of budget strings",
+                        group, function
+                    ))
+                };
+                let values = match value {
+                    toml::Value::String(budget) => vec![budget.as_str()],
+                    toml::Value::Array(array) => array
+                        .iter()
+                        .map(|budget| budget.as_str().ok_or_else(invalid))
+                        .collect::<Result<Vec<&str>, ParseBudgetError>>()?,
+                    _ => return Err(invalid()),
+                };
+                for budget in values {
+                    budgets.push(group, function, budget.parse::<Budget>()?);
+                }
+            }
+        }
+        Ok(budgets)
+    }
+}
+
+impl BudgetResult {
+    pub fn group(&self) -> &GroupName {
+        &self.group
+    }
+
+    pub fn function(&self) -> &FnName {
+        &self.function
+    }
+
+    pub fn budget(&self) -> &Budget {
+        &self.budget
+    }
+
+    /// The measured statistic in nanoseconds
+    pub fn measured(&self) -> Measure {
+        self.measured
+    }
+
+    /// The measured statistic in the time unit of the budget
+    pub fn measured_in_budget_unit(&self) -> f64 {
+        TimeUnit::NS.convert(self.measured, self.budget.unit())
+    }
+
+    pub fn passed(&self) -> bool {
+        self.budget.passes(self.measured)
+    }
+}
+
+impl Display for BudgetResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}/{}: {} {:.3} {} (budget {})",
+            if self.passed() { "PASS" } else { "FAIL" },
+            self.group,
+            self.function,
+            self.budget.statistic(),
+            self.measured_in_budget_unit(),
+            self.budget.unit,
+            self.budget
+        )
+    }
+}
+
+impl BudgetReport {
+    pub fn results(&self) -> &Vec<BudgetResult> {
+        &self.results
+    }
+
+    pub fn failures(&self) -> Vec<&BudgetResult> {
+        self.results
+            .iter()
+            .filter(|result| !result.passed())
+            .collect()
+    }
+
+    pub fn has_failures(&self) -> bool {
+        self.results.iter().any(|result| !result.passed())
+    }
+}
+
+impl Display for BudgetReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for result in &self.results {
+            writeln!(f, "{}", result)?;
+        }
+        write!(
+            f,
+            "{} budgets passed, {} failed",
+            self.results.len() - self.failures().len(),
+            self.failures().len()
+        )
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::timeunit::TimeUnit;
+
+    #[test]
+    fn test_budgets() {
+        let budgets = r#"
+            # Push budgets
+            [Push]
+            vec-push = ["p95 < 120 ns", "mean <= 2 µs"]
+            "*" = "max < 1ms" # every function
+
+            ["pop"]
+            fll-push = [
+                "median < 5 ns",
+            ]
+        "#
+        .parse::<Budgets>()
+        .unwrap();
+
+        let vec_push = budgets.get("push", "Vec-Push");
+        assert_eq!(3, vec_push.len());
+        assert_eq!(Statistic::Percentile(95.0), vec_push[0].statistic());
+        assert_eq!("<=", vec_push[1].operator());
+        assert_eq!(2_000.0, vec_push[1].limit_nanos());
+        assert_eq!(1_000_000.0, vec_push[2].limit_nanos());
+        assert_eq!(1, budgets.get("push", "fll-push").len());
+
+        let mut cdata = CriterionData::new();
+        for measurement in [4.0, 6.0, 5.0, 7.0] {
+            cdata.insert(
+                "Pop".to_string(),
+                "Fll-Push".to_string(),
+                measurement,
+                1,
+                TimeUnit::NS,
+                None,
+            );
+        }
+        let report = budgets.evaluate(&cdata);
+        assert_eq!(1, report.results().len());
+        assert_eq!(5.5, report.results()[0].measured());
+        assert!(report.has_failures());
+
+        let budgets = r#"
+            push = { "vec, push" = ["p95 < 120 us", "mean < 2 \u00b5s"] } # inline
+            [pop]
+            "fll\tpop" = 'median < 5 ns'
+        "#
+        .parse::<Budgets>()
+        .unwrap();
+        let vec_push = budgets.get("push", "vec, push");
+        assert_eq!(2, vec_push.len());
+        assert_eq!(120_000.0, vec_push[0].limit_nanos());
+        assert_eq!(TimeUnit::US, vec_push[1].unit());
+        assert_eq!(1, budgets.get("pop", "fll\tpop").len());
+
+        assert!("[push]\nvec-push = \"p95 > 1 ns\""
+            .parse::<Budgets>()
+            .is_err());
+        assert!("vec-push = \"p95 < 1 ns\"".parse::<Budgets>().is_err());
+        assert!("[push]\nvec-push = \"p95 < 1 days\""
+            .parse::<Budgets>()
+            .is_err());
+        assert!("[push]\nvec-push = 120".parse::<Budgets>().is_err());
+        assert!("[push]\nvec-push = [\"p95 < 1 ns\", 2]"
+            .parse::<Budgets>()
+            .is_err());
+        assert!("[push\nvec-push = \"p95 < 1 ns\""
+            .parse::<Budgets>()
+            .is_err());
+    }
+}
@@ -1,13 +1,31 @@
+use crate::budgets::{BudgetReport, Budgets};
+use crate::chart::Chart;
 use crate::cplot::CriterionPlots;
+use crate::meanband::MeanBand;
+use crate::pdfmode::PdfMode;
+use crate::plotly::PlotlyFigure;
+use crate::plotscript::{PlotScript, ScriptFormat};
+use crate::svg::SvgChart;
+use crate::timeunit::TimeUnit;
 use crate::tsxcode;
-use crate::tsxdata::ToTsxFile;
+use crate::tsxdata::{SuiteScores, ToTsxFile};
+use crate::vegalite::VegaLiteSpec;
 use crate::InvalidPath;
 use std::{error::Error, fs::File, io::Write, path::Path};
 
+const MARKDOWN_REPORT_TITLE: &str = "Benchmark Results";
+const JUNIT_REPORT_NAME: &str = "benchmarks";
+
 pub struct CriterionChartsTsx {
     cplots: CriterionPlots,
 }
 
+impl Default for CriterionChartsTsx {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CriterionChartsTsx {
     pub fn new() -> CriterionChartsTsx {
         CriterionChartsTsx {
@@ -19,6 +37,50 @@ impl CriterionChartsTsx {
         self.cplots.load_raw_data(filepath)
     }
 
+    pub fn load_baseline(&mut self, filepath: &Path) -> Result<(), Box<dyn Error>> {
+        self.cplots.load_baseline_raw_data(filepath)
+    }
+
+    pub fn set_scores(&mut self, scores: &[(&str, &str)]) {
+        self.cplots.set_scores(scores);
+    }
+
+    pub fn suite_scores(&self) -> SuiteScores {
+        self.cplots.suite_scores()
+    }
+
+    pub fn set_budgets(&mut self, budgets: Budgets) {
+        self.cplots.set_budgets(budgets);
+    }
+
+    pub fn budget_report(&self) -> BudgetReport {
+        self.cplots.budget_report()
+    }
+
+    pub fn set_pdf_mode(&mut self, pdf_mode: PdfMode) {
+        self.cplots.set_pdf_mode(pdf_mode);
+    }
+
+    pub fn set_group_pdf_mode(&mut self, group: &str, pdf_mode: PdfMode) {
+        self.cplots.set_group_pdf_mode(group, pdf_mode);
+    }
+
+    pub fn set_mean_band(&mut self, mean_band: MeanBand) {
+        self.cplots.set_mean_band(mean_band);
+    }
+
+    pub fn set_relative_to(&mut self, relative_to: &[&str]) {
+        self.cplots.set_relative_to(relative_to);
+    }
+
+    pub fn set_time_unit(&mut self, time_unit: TimeUnit) {
+        self.cplots.set_time_unit(time_unit);
+    }
+
+    pub fn set_series_color(&mut self, fn_name: &str, color: &str) {
+        self.cplots.set_series_color(fn_name, color);
+    }
+
     fn generate_ccharts_tsx_file(&self, dir_path: &Path) -> Result<(), Box<dyn Error>> {
         let filename = format!("ccharts.{}.tsx", tsxcode::VERSION);
         let mut filepath = dir_path.to_path_buf();
@@ -29,6 +91,144 @@ impl CriterionChartsTsx {
         Ok(())
     }
 
+    fn generate_suite_tsx_file(&self, dir_path: &Path) -> Result<(), Box<dyn Error>> {
+        let filename = "ccharts.suite.tsx";
+        let mut filepath = dir_path.to_path_buf();
+        filepath.extend(Path::new(&filename));
+        println!("Generating Suite data file: {}", filepath.display());
+        let mut tsxfile = File::create(filepath)?;
+        writeln!(tsxfile, "{}", tsxcode::TSX_HEADER)?;
+        write!(tsxfile, "{}", tsxcode::SUITE_COMPONENTS_IMPORTS)?;
+        write!(tsxfile, ".{}", tsxcode::VERSION)?;
+        writeln!(tsxfile, "';")?;
+        writeln!(tsxfile, "{}", tsxcode::SUITE_COMPONENTS_TSX)?;
+        self.cplots.suite_matrix().to_tsx_file(&mut tsxfile)?;
+        Ok(())
+    }
+
+    fn generate_score_tsx_file(
+        &self,
+        dir_path: &Path,
+        scores: &SuiteScores,
+    ) -> Result<(), Box<dyn Error>> {
+        let filename = "ccharts.score.tsx";
+        let mut filepath = dir_path.to_path_buf();
+        filepath.extend(Path::new(&filename));
+        println!("Generating Score data file: {}", filepath.display());
+        let mut tsxfile = File::create(filepath)?;
+        writeln!(tsxfile, "{}", tsxcode::TSX_HEADER)?;
+        write!(tsxfile, "{}", tsxcode::SCORE_IMPORTS)?;
+        write!(tsxfile, ".{}", tsxcode::VERSION)?;
+        writeln!(tsxfile, "';")?;
+        scores.to_tsx_file(&mut tsxfile)?;
+        Ok(())
+    }
+
+    pub fn generate_markdown(&self, filepath: &Path) -> Result<(), Box<dyn Error>> {
+        println!("Generating Markdown report: {}", filepath.display());
+        self.cplots
+            .markdown_report(MARKDOWN_REPORT_TITLE)
+            .write(filepath)
+    }
+
+    pub fn generate_html(&self, filepath: &Path, title: &str) -> Result<(), Box<dyn Error>> {
+        println!("Generating HTML report: {}", filepath.display());
+        self.cplots.html_report(title).write(filepath)
+    }
+
+    pub fn generate_junit(&self, filepath: &Path) -> Result<(), Box<dyn Error>> {
+        println!("Generating JUnit report: {}", filepath.display());
+        self.cplots.junit_report(JUNIT_REPORT_NAME).write(filepath)
+    }
+
+    pub fn generate_summary_json(&self, filepath: &Path) -> Result<(), Box<dyn Error>> {
+        println!("Generating JSON summary: {}", filepath.display());
+        self.cplots.suite_summary().write_json(filepath)
+    }
+
+    pub fn generate_summary_csv(&self, filepath: &Path) -> Result<(), Box<dyn Error>> {
+        println!("Generating CSV summary: {}", filepath.display());
+        self.cplots.suite_summary().write_csv(filepath)
+    }
+
+    /// Writes every chart of every group to <group>-<chart>.<extension>
+    fn generate_charts<F>(
+        &self,
+        dir_path: &Path,
+        extension: &str,
+        description: &str,
+        write_chart: F,
+    ) -> Result<(), Box<dyn Error>>
+    where
+        F: Fn(&Chart, &Path) -> Result<(), Box<dyn Error>>,
+    {
+        if !dir_path.is_dir() {
+            return Err(InvalidPath::new(dir_path).into());
+        }
+        for plot in self.cplots.plots() {
+            for chart in plot.charts(self.cplots.chart_style()) {
+                let filename = format!("{}-{}.{}", chart.group(), chart.kind().name(), extension);
+                let mut filepath = dir_path.to_path_buf();
+                filepath.extend(Path::new(&filename));
+                println!("Generating {}: {}", description, filepath.display());
+                write_chart(&chart, &filepath)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the MT, LR and PDF charts of every group as <group>-<chart>.svg
+    pub fn generate_svg(&self, dir_path: &Path) -> Result<(), Box<dyn Error>> {
+        self.generate_charts(dir_path, "svg", "SVG chart", |chart, filepath| {
+            SvgChart::new(chart).write(filepath)
+        })
+    }
+
+    /// Writes a Vega-Lite specification of the MT, LR and PDF charts of
+    /// every group as <group>-<chart>.vl.json
+    pub fn generate_vega_lite(&self, dir_path: &Path) -> Result<(), Box<dyn Error>> {
+        self.generate_charts(dir_path, "vl.json", "Vega-Lite spec", |chart, filepath| {
+            VegaLiteSpec::new(chart).write(filepath)
+        })
+    }
+
+    /// Writes a Plotly figure of the MT, LR and PDF charts of every group as
+    /// <group>-<chart>.plotly.json
+    pub fn generate_plotly(&self, dir_path: &Path) -> Result<(), Box<dyn Error>> {
+        self.generate_charts(
+            dir_path,
+            "plotly.json",
+            "Plotly figure",
+            |chart, filepath| PlotlyFigure::new(chart).write(filepath),
+        )
+    }
+
+    /// Writes a gnuplot script and a data file of the MT, LR and PDF charts
+    /// of every group as <group>-<chart>.gp and <group>-<chart>.dat
+    pub fn generate_gnuplot(&self, dir_path: &Path) -> Result<(), Box<dyn Error>> {
+        self.generate_plot_scripts(dir_path, ScriptFormat::Gnuplot, "gnuplot script")
+    }
+
+    /// Writes a pgfplots snippet and a data file of the MT, LR and PDF charts
+    /// of every group as <group>-<chart>.tex and <group>-<chart>.dat
+    pub fn generate_pgfplots(&self, dir_path: &Path) -> Result<(), Box<dyn Error>> {
+        self.generate_plot_scripts(dir_path, ScriptFormat::Pgfplots, "pgfplots snippet")
+    }
+
+    fn generate_plot_scripts(
+        &self,
+        dir_path: &Path,
+        format: ScriptFormat,
+        description: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        self.generate_charts(
+            dir_path,
+            format.extension(),
+            description,
+            |chart, filepath| PlotScript::new(chart, format).write(filepath),
+        )
+    }
+
     pub fn generate_tsx(&self, dir_path: &Path) -> Result<(), Box<dyn Error>> {
         if !dir_path.is_dir() {
             return Err(InvalidPath::new(dir_path).into());
@@ -43,6 +243,11 @@ impl CriterionChartsTsx {
             let mut tsxfile = File::create(filepath)?;
             plot.to_tsx_file(&mut tsxfile)?;
         }
+        self.generate_suite_tsx_file(dir_path)?;
+        let scores = self.suite_scores();
+        if !scores.is_empty() {
+            self.generate_score_tsx_file(dir_path, &scores)?;
+        }
         self.generate_ccharts_tsx_file(dir_path)?;
         Ok(())
     }
@@ -0,0 +1,493 @@
+use crate::timeunit::TimeUnit;
+use crate::tsxdata::{PdfDataSet, PdfValue, TsxDataSet};
+use crate::FnName;
+use crate::GroupName;
+use crate::YIndex;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Display;
+
+const DEFAULT_COLORS: [&str; 10] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f",
+    "#bcbd22", "#17becf",
+];
+
+/// The charts of a group that can be rendered without the TSX components
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ChartKind {
+    /// The mean time per iteration of every sample
+    MeanTime,
+    /// The total time of every sample with the trendline of each function
+    LinearRegression,
+    /// The probability density of the mean time per iteration with the mean
+    /// of each function
+    Pdf,
+}
+
+/// How a layer of a chart is drawn
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Mark {
+    /// A dot at every point
+    Points,
+    /// A line through the points
+    Line,
+    /// A line through the points filled down to zero
+    Area,
+    /// Steps that start at each point filled down to zero
+    StepArea,
+}
+
+/// The points of one function drawn with one mark
+pub struct ChartLayer {
+    name: String,
+    fn_name: FnName,
+    mark: Mark,
+    color: String,
+    points: Vec<(f64, f64)>,
+}
+
+/// A vertical reference line e.g. the mean of a function
+pub struct ChartRule {
+    name: String,
+    fn_name: FnName,
+    color: String,
+    x: f64,
+}
+
+/// The display time unit and the series colors of the charts
+pub struct ChartStyle {
+    time_unit: Option<TimeUnit>,
+    colors: BTreeMap<FnName, String>,
+}
+
+/// A chart of a group with its values converted to the display time unit
+pub struct Chart {
+    group: GroupName,
+    kind: ChartKind,
+    time_unit: TimeUnit,
+    layers: Vec<ChartLayer>,
+    rules: Vec<ChartRule>,
+}
+
+impl ChartKind {
+    /// The short name of the chart used in file names
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChartKind::MeanTime => "mt",
+            ChartKind::LinearRegression => "lr",
+            ChartKind::Pdf => "pdf",
+        }
+    }
+
+    /// Matches getXLabelValue in the TSX components
+    pub fn x_label(&self, time_unit: TimeUnit) -> String {
+        match self {
+            ChartKind::MeanTime | ChartKind::LinearRegression => "Iterations".to_string(),
+            ChartKind::Pdf => format!("Mean Time Per Iteration ({})", time_unit),
+        }
+    }
+
+    /// Matches getYLabelValue in the TSX components
+    pub fn y_label(&self, time_unit: TimeUnit) -> String {
+        match self {
+            ChartKind::MeanTime => format!("Mean Time Per Iteration ({})", time_unit),
+            ChartKind::LinearRegression => format!("Total Sample Time ({})", time_unit),
+            ChartKind::Pdf => "Density".to_string(),
+        }
+    }
+}
+
+impl Display for ChartKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChartKind::MeanTime => write!(f, "Mean Time Per Iteration"),
+            ChartKind::LinearRegression => write!(f, "Linear Regression"),
+            ChartKind::Pdf => write!(f, "Probability Density"),
+        }
+    }
+}
+
+impl ChartLayer {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn fn_name(&self) -> &FnName {
+        &self.fn_name
+    }
+
+    pub fn mark(&self) -> Mark {
+        self.mark
+    }
+
+    pub fn color(&self) -> &String {
+        &self.color
+    }
+
+    pub fn points(&self) -> &Vec<(f64, f64)> {
+        &self.points
+    }
+}
+
+impl ChartRule {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn fn_name(&self) -> &FnName {
+        &self.fn_name
+    }
+
+    pub fn color(&self) -> &String {
+        &self.color
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+}
+
+impl Default for ChartStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChartStyle {
+    pub fn new() -> ChartStyle {
+        ChartStyle {
+            time_unit: None,
+            colors: BTreeMap::<FnName, String>::new(),
+        }
+    }
+
+    /// Sets the time unit that the charts are displayed in. Defaults to the
+    /// time unit of the data.
+    pub fn set_time_unit(&mut self, time_unit: TimeUnit) {
+        self.time_unit = Some(time_unit);
+    }
+
+    /// Sets the color of a function in every group
+    pub fn set_color(&mut self, fn_name: &str, color: &str) {
+        self.colors
+            .insert(fn_name.to_ascii_lowercase(), color.to_string());
+    }
+
+    /// The color set for the function or a color from the default palette
+    pub fn color(&self, fn_name: &str, y_index: YIndex) -> String {
+        match self.colors.get(fn_name) {
+            Some(color) => color.to_string(),
+            None => DEFAULT_COLORS[y_index as usize % DEFAULT_COLORS.len()].to_string(),
+        }
+    }
+
+    pub fn time_unit(&self, data_unit: TimeUnit) -> TimeUnit {
+        self.time_unit.unwrap_or(data_unit)
+    }
+}
+
+impl Chart {
+    fn new(group: &str, kind: ChartKind, time_unit: TimeUnit) -> Chart {
+        Chart {
+            group: group.to_string(),
+            kind,
+            time_unit,
+            layers: Vec::<ChartLayer>::new(),
+            rules: Vec::<ChartRule>::new(),
+        }
+    }
+
+    /// Drops the points that are not finite e.g. the densities of a sample
+    /// without variance, and the layer if no points are left
+    fn push_layer(
+        &mut self,
+        name: String,
+        fn_name: &str,
+        mark: Mark,
+        color: &str,
+        mut points: Vec<(f64, f64)>,
+    ) {
+        points.retain(|(x, y)| x.is_finite() && y.is_finite());
+        if points.is_empty() {
+            return;
+        }
+        self.layers.push(ChartLayer {
+            name,
+            fn_name: fn_name.to_string(),
+            mark,
+            color: color.to_string(),
+            points,
+        });
+    }
+
+    /// The MT chart from the mean time data of a group
+    pub fn mean_time(group: &str, dataset: &TsxDataSet, style: &ChartStyle) -> Chart {
+        let data_unit = dataset.time_unit().unwrap_or(TimeUnit::NS);
+        let mut chart = Chart::new(group, ChartKind::MeanTime, style.time_unit(data_unit));
+        for (y_index, fn_name) in dataset.functions() {
+            let points = dataset
+                .points(y_index)
+                .iter()
+                .map(|(iter_count, val)| {
+                    (*iter_count as f64, data_unit.convert(*val, chart.time_unit))
+                })
+                .collect();
+            let color = style.color(fn_name, y_index);
+            chart.push_layer(fn_name.to_string(), fn_name, Mark::Line, &color, points);
+        }
+        chart
+    }
+
+    /// The LR chart from the total time data of a group. The trendlines are
+    /// included if the dataset has them.
+    pub fn linear_regression(group: &str, dataset: &TsxDataSet, style: &ChartStyle) -> Chart {
+        let data_unit = dataset.time_unit().unwrap_or(TimeUnit::NS);
+        let mut chart = Chart::new(
+            group,
+            ChartKind::LinearRegression,
+            style.time_unit(data_unit),
+        );
+        let time_unit = chart.time_unit;
+        for (y_index, fn_name) in dataset.functions() {
+            let color = style.color(fn_name, y_index);
+            let points = dataset
+                .points(y_index)
+                .iter()
+                .map(|(iter_count, val)| (*iter_count as f64, data_unit.convert(*val, time_unit)))
+                .collect();
+            chart.push_layer(fn_name.to_string(), fn_name, Mark::Points, &color, points);
+            if let Some(trendline) = dataset.trendline(y_index) {
+                let points = vec![
+                    (
+                        trendline.x_start(),
+                        data_unit.convert(trendline.y_start(), time_unit),
+                    ),
+                    (
+                        trendline.x_end(),
+                        data_unit.convert(trendline.y_end(), time_unit),
+                    ),
+                ];
+                chart.push_layer(
+                    format!("LR ({})", fn_name),
+                    fn_name,
+                    Mark::Line,
+                    &color,
+                    points,
+                );
+            }
+        }
+        chart
+    }
+
+    /// The PDF chart from the mean time data of a group and the PDF data
+    /// derived from it. Draws the KDE or the histogram, whichever the PDF
+    /// data has, and the mean of each function.
+    pub fn pdf(
+        group: &str,
+        dataset: &TsxDataSet,
+        pdf_data: &PdfDataSet,
+        style: &ChartStyle,
+    ) -> Chart {
+        let data_unit = dataset.time_unit().unwrap_or(TimeUnit::NS);
+        let mut chart = Chart::new(group, ChartKind::Pdf, style.time_unit(data_unit));
+        let time_unit = chart.time_unit;
+        for (y_index, fn_name) in dataset.functions() {
+            let color = style.color(fn_name, y_index);
+            let mut kde = Vec::<(f64, f64)>::new();
+            let mut histogram = Vec::<(f64, f64)>::new();
+            for (x_val, value) in pdf_data.values(y_index) {
+                let x_val = data_unit.convert(x_val, time_unit);
+                match value {
+                    PdfValue::Kde(density) => kde.push((x_val, density)),
+                    PdfValue::Histogram(density, _) => histogram.push((x_val, density)),
+                    PdfValue::Measurement(_) => {}
+                }
+            }
+            if !kde.is_empty() {
+                chart.push_layer(
+                    format!("Density ({})", fn_name),
+                    fn_name,
+                    Mark::Area,
+                    &color,
+                    kde,
+                );
+            }
+            if !histogram.is_empty() {
+                chart.push_layer(
+                    format!("Histogram ({})", fn_name),
+                    fn_name,
+                    Mark::StepArea,
+                    &color,
+                    histogram,
+                );
+            }
+            if let Some(uva) = dataset.uv_analysis(y_index) {
+                chart.rules.push(ChartRule {
+                    name: format!("Mean ({})", fn_name),
+                    fn_name: fn_name.to_string(),
+                    color,
+                    x: data_unit.convert(uva.mean(), time_unit),
+                });
+            }
+        }
+        chart
+    }
+
+    pub fn group(&self) -> &GroupName {
+        &self.group
+    }
+
+    pub fn kind(&self) -> ChartKind {
+        self.kind
+    }
+
+    pub fn time_unit(&self) -> TimeUnit {
+        self.time_unit
+    }
+
+    pub fn title(&self) -> String {
+        format!("{}: {}", self.group, self.kind)
+    }
+
+    pub fn x_label(&self) -> String {
+        self.kind.x_label(self.time_unit)
+    }
+
+    pub fn y_label(&self) -> String {
+        self.kind.y_label(self.time_unit)
+    }
+
+    pub fn layers(&self) -> &Vec<ChartLayer> {
+        &self.layers
+    }
+
+    pub fn rules(&self) -> &Vec<ChartRule> {
+        &self.rules
+    }
+
+    /// The name and color of every function in the order they were added
+    pub fn legend(&self) -> Vec<(&FnName, &String)> {
+        let mut legend = Vec::<(&FnName, &String)>::new();
+        let entries = self
+            .layers
+            .iter()
+            .map(|layer| (&layer.fn_name, &layer.color))
+            .chain(self.rules.iter().map(|rule| (&rule.fn_name, &rule.color)));
+        for (fn_name, color) in entries {
+            if !legend.iter().any(|(name, _)| *name == fn_name) {
+                legend.push((fn_name, color));
+            }
+        }
+        legend
+    }
+
+    /// The smallest and largest x of every layer and rule
+    pub fn x_range(&self) -> (f64, f64) {
+        let xs = self
+            .layers
+            .iter()
+            .flat_map(|layer| layer.points.iter().map(|(x, _)| *x))
+            .chain(self.rules.iter().map(|rule| rule.x));
+        range(xs)
+    }
+
+    /// The smallest and largest y of every layer. Areas are filled down to
+    /// zero so zero is included if the chart has any.
+    pub fn y_range(&self) -> (f64, f64) {
+        let has_area = self
+            .layers
+            .iter()
+            .any(|layer| matches!(layer.mark, Mark::Area | Mark::StepArea));
+        let ys = self
+            .layers
+            .iter()
+            .flat_map(|layer| layer.points.iter().map(|(_, y)| *y))
+            .chain(has_area.then_some(0.0));
+        range(ys)
+    }
+}
+
+/// The smallest and largest finite value. (0, 1) if there are none.
+fn range(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let (min, max) = values
+        .filter(|val| val.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), val| {
+            (min.min(val), max.max(val))
+        });
+    if min > max {
+        return (0.0, 1.0);
+    }
+    (min, max)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::rawdata::CriterionData;
+    use crate::tsxdata::TsxData;
+
+    #[test]
+    fn test_charts() {
+        let mut cdata = CriterionData::new();
+        for (i, measurement) in [1000.0, 2100.0, 2900.0, 4000.0].iter().enumerate() {
+            cdata.insert(
+                "Push".to_string(),
+                "Vec".to_string(),
+                *measurement,
+                (i + 1) as u32,
+                TimeUnit::NS,
+                None,
+            );
+        }
+        let mut style = ChartStyle::new();
+        style.set_time_unit(TimeUnit::MS);
+        style.set_color("VEC", "#000000");
+
+        let mt_data = cdata.mean_time_data(None).unwrap();
+        let dataset = mt_data.get("push").unwrap();
+        let chart = Chart::mean_time("push", dataset, &style);
+        assert_eq!("Mean Time Per Iteration (ms)", chart.y_label());
+        assert_eq!("Iterations", chart.x_label());
+        assert_eq!(1, chart.layers().len());
+        assert_eq!("#000000", chart.layers()[0].color());
+        assert_eq!((1.0, 4.0), chart.x_range());
+        assert!((chart.y_range().0 - 0.000_966_666).abs() < 1e-6);
+
+        let tt_data = cdata.total_time_data(None).unwrap();
+        let chart = Chart::linear_regression("push", tt_data.get("push").unwrap(), &style);
+        assert_eq!(2, chart.layers().len());
+        assert_eq!(Mark::Line, chart.layers()[1].mark());
+        assert_eq!("LR (vec)", chart.layers()[1].name());
+
+        let pdf_data = mt_data.data().pdf_data("push").unwrap();
+        let chart = Chart::pdf("push", dataset, &pdf_data, &ChartStyle::new());
+        assert_eq!("Mean Time Per Iteration (ns)", chart.x_label());
+        assert_eq!(Mark::Area, chart.layers()[0].mark());
+        assert_eq!(0.0, chart.y_range().0);
+        assert_eq!(1, chart.rules().len());
+        assert_eq!(
+            vec![(&"vec".to_string(), &"#1f77b4".to_string())],
+            chart.legend()
+        );
+
+        //A sample without variance has no finite densities
+        for i in 1..5 {
+            cdata.insert(
+                "Push".to_string(),
+                "Deque".to_string(),
+                10.0 * i as f64,
+                i,
+                TimeUnit::NS,
+                None,
+            );
+        }
+        let mt_data = cdata.mean_time_data(None).unwrap();
+        let dataset = mt_data.get("push").unwrap();
+        let pdf_data = mt_data.data().pdf_data("push").unwrap();
+        let chart = Chart::pdf("push", dataset, &pdf_data, &ChartStyle::new());
+        assert!(chart.layers().iter().all(|layer| layer.fn_name() == "vec"));
+        assert_eq!(2, chart.rules().len());
+        assert_eq!(2, chart.legend().len());
+    }
+}
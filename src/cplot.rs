@@ -1,17 +1,37 @@
 use crate::{
-    infomap::SeriesInfoMap,
+    budgets::{BudgetReport, Budgets},
+    chart::{Chart, ChartStyle},
+    html::HtmlReport,
+    infomap::{BudgetMap, ComparisonMap, ComplexityMap, SeriesInfoMap},
+    junit::JUnitReport,
+    markdown::MarkdownReport,
+    meanband::MeanBand,
+    pdfmode::PdfMode,
     rawdata::CriterionData,
+    regression::RegressionCheck,
+    summary::SuiteSummary,
+    timeunit::TimeUnit,
     tsxcode,
-    tsxdata::{MeanTimeData, ToTsxFile, TotalTimeData},
-    GroupName, InvalidPath,
+    tsxdata::{
+        ComplexityData, MeanTimeData, SuiteMatrix, SuiteScores, ToTsxFile, TotalTimeData, TsxData,
+    },
+    FnName, GroupName, InvalidPath,
 };
 
-use std::{error::Error, fs::File, io::Write, path::Path};
+use std::{collections::BTreeMap, error::Error, fs::File, io::Write, path::Path};
 
 type CPlotResult<T> = std::result::Result<T, Box<dyn Error>>;
 
 pub struct CriterionPlots {
     cdata: CriterionData,
+    pdf_mode: PdfMode,
+    group_pdf_modes: BTreeMap<GroupName, PdfMode>,
+    mean_band: Option<MeanBand>,
+    relative_to: Vec<FnName>,
+    baseline: CriterionData,
+    scores: Vec<(FnName, FnName)>,
+    budgets: Budgets,
+    chart_style: ChartStyle,
 }
 
 pub struct CriterionPlot {
@@ -19,6 +39,10 @@ pub struct CriterionPlot {
     mean_time_data: Option<MeanTimeData>,
     total_time_data: Option<TotalTimeData>,
     series_info_map: Option<SeriesInfoMap>,
+    comparison_map: Option<ComparisonMap>,
+    complexity_map: Option<ComplexityMap>,
+    complexity_data: Option<ComplexityData>,
+    budget_map: Option<BudgetMap>,
 }
 
 impl CriterionPlot {
@@ -26,8 +50,12 @@ impl CriterionPlot {
         CriterionPlot {
             group: group.to_string(),
             series_info_map: None,
+            comparison_map: None,
+            complexity_map: None,
+            complexity_data: None,
             mean_time_data: None,
             total_time_data: None,
+            budget_map: None,
         }
     }
 
@@ -43,15 +71,68 @@ impl CriterionPlot {
         self.series_info_map = si_map
     }
 
+    pub fn set_cmp_map(&mut self, cmp_map: Option<ComparisonMap>) {
+        self.comparison_map = cmp_map
+    }
+
+    pub fn set_cx_map(&mut self, cx_map: Option<ComplexityMap>) {
+        self.complexity_map = cx_map
+    }
+
+    pub fn set_cx_data(&mut self, cx_data: Option<ComplexityData>) {
+        self.complexity_data = cx_data
+    }
+
+    pub fn set_budget_map(&mut self, budget_map: Option<BudgetMap>) {
+        self.budget_map = budget_map
+    }
+
     pub fn group(&self) -> &GroupName {
         &self.group
     }
+
+    /// The MT, LR and PDF charts of the group for the static backends
+    pub fn charts(&self, style: &ChartStyle) -> Vec<Chart> {
+        let mut charts = Vec::<Chart>::new();
+        let mt_data = self.mean_time_data.as_ref();
+        let mt_dataset = mt_data.and_then(|mt_data| mt_data.get(&self.group));
+        if let Some(dataset) = mt_dataset {
+            charts.push(Chart::mean_time(&self.group, dataset, style));
+        }
+        if let Some(dataset) = self
+            .total_time_data
+            .as_ref()
+            .and_then(|tt_data| tt_data.get(&self.group))
+        {
+            charts.push(Chart::linear_regression(&self.group, dataset, style));
+        }
+        if let (Some(mt_data), Some(dataset)) = (mt_data, mt_dataset) {
+            if let Some(pdf_data) = mt_data.data().pdf_data(&self.group) {
+                charts.push(Chart::pdf(&self.group, dataset, &pdf_data, style));
+            }
+        }
+        charts
+    }
+}
+
+impl Default for CriterionPlots {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl CriterionPlots {
     pub fn new() -> CriterionPlots {
         CriterionPlots {
             cdata: CriterionData::new(),
+            pdf_mode: PdfMode::default(),
+            group_pdf_modes: BTreeMap::<GroupName, PdfMode>::new(),
+            mean_band: None,
+            relative_to: Vec::<FnName>::new(),
+            baseline: CriterionData::new(),
+            scores: Vec::<(FnName, FnName)>::new(),
+            budgets: Budgets::new(),
+            chart_style: ChartStyle::new(),
         }
     }
 
@@ -61,7 +142,10 @@ impl CriterionPlots {
             cdata.load(filepath)?;
         }
 
-        Ok(CriterionPlots { cdata: cdata })
+        Ok(CriterionPlots {
+            cdata,
+            ..CriterionPlots::new()
+        })
     }
 
     pub fn load_raw_data(&mut self, filepath: &Path) -> CPlotResult<()> {
@@ -72,6 +156,152 @@ impl CriterionPlots {
         Err(InvalidPath::new(filepath).into())
     }
 
+    /// Loads the raw data of a previous run that the suite score compares
+    /// the loaded data against
+    pub fn load_baseline_raw_data(&mut self, filepath: &Path) -> CPlotResult<()> {
+        if let Some(filepath) = filepath.to_str() {
+            self.baseline.load(filepath)?;
+            return Ok(());
+        }
+        Err(InvalidPath::new(filepath).into())
+    }
+
+    /// Sets the pairs of functions that are scored across the whole suite
+    pub fn set_scores(&mut self, scores: &[(&str, &str)]) {
+        self.scores = scores
+            .iter()
+            .map(|(fn_a, fn_b)| (fn_a.to_string(), fn_b.to_string()))
+            .collect();
+    }
+
+    pub fn set_budgets(&mut self, budgets: Budgets) {
+        self.budgets = budgets;
+    }
+
+    /// Evaluates the budgets against the loaded data
+    pub fn budget_report(&self) -> BudgetReport {
+        self.budgets.evaluate(&self.cdata)
+    }
+
+    /// Sets the PDF mode of every group that doesn't have its own mode set
+    pub fn set_pdf_mode(&mut self, pdf_mode: PdfMode) {
+        self.pdf_mode = pdf_mode;
+    }
+
+    pub fn set_group_pdf_mode(&mut self, group: &str, pdf_mode: PdfMode) {
+        self.group_pdf_modes
+            .insert(group.to_ascii_lowercase(), pdf_mode);
+    }
+
+    /// Emits the band around the mean in the mean time data. No band data is
+    /// emitted unless this is set.
+    pub fn set_mean_band(&mut self, mean_band: MeanBand) {
+        self.mean_band = Some(mean_band);
+    }
+
+    /// Sets the time unit of the static charts. Defaults to the time unit of
+    /// the data.
+    pub fn set_time_unit(&mut self, time_unit: TimeUnit) {
+        self.chart_style.set_time_unit(time_unit);
+    }
+
+    /// Sets the color of a function in the static charts
+    pub fn set_series_color(&mut self, fn_name: &str, color: &str) {
+        self.chart_style.set_color(fn_name, color);
+    }
+
+    pub fn chart_style(&self) -> &ChartStyle {
+        &self.chart_style
+    }
+
+    /// Sets the functions that the other functions of a group are measured
+    /// against. The first one found in a group is its baseline.
+    pub fn set_relative_to(&mut self, relative_to: &[&str]) {
+        self.relative_to = relative_to.iter().map(|f| f.to_string()).collect();
+    }
+
+    fn pdf_mode(&self, group: &str) -> PdfMode {
+        match self.group_pdf_modes.get(group) {
+            Some(pdf_mode) => *pdf_mode,
+            None => self.pdf_mode,
+        }
+    }
+
+    fn mean_time_data(&self, group_name: Option<&str>) -> Option<MeanTimeData> {
+        let mut mt_data = self.cdata.mean_time_data(group_name)?;
+        for group in self.cdata.groups() {
+            mt_data.set_pdf_mode(&group, self.pdf_mode(&group));
+        }
+        if let Some(mean_band) = self.mean_band {
+            mt_data.set_mean_band(mean_band);
+        }
+        mt_data.set_relative_to(&self.relative_to);
+        Some(mt_data)
+    }
+
+    pub fn suite_matrix(&self) -> SuiteMatrix {
+        self.cdata.suite_matrix(&self.relative_to)
+    }
+
+    /// The score of every pair of functions set with `set_scores`, followed
+    /// by the score of the loaded data against the baseline run if one was
+    /// loaded.
+    pub fn suite_scores(&self) -> SuiteScores {
+        let mut scores = SuiteScores::new();
+        for (fn_a, fn_b) in &self.scores {
+            if let Some(score) = self.cdata.suite_score(fn_a, fn_b) {
+                scores.push(score);
+            }
+        }
+        if let Some(score) = self.cdata.baseline_score(&self.baseline) {
+            scores.push(score);
+        }
+        scores
+    }
+
+    /// A Markdown summary of the loaded data. The change of every function
+    /// is included if a baseline run was loaded.
+    pub fn markdown_report(&self, title: &str) -> MarkdownReport {
+        let mut report = MarkdownReport::new(title, self.cdata.stats_info_map());
+        if !self.baseline.is_empty() {
+            report.set_regression_report(RegressionCheck::new().check(&self.cdata, &self.baseline));
+        }
+        report
+    }
+
+    /// An HTML page with the stats and the static charts of every group. The
+    /// change of every function is included if a baseline run was loaded.
+    pub fn html_report(&self, title: &str) -> HtmlReport {
+        let mut report = HtmlReport::new(title, self.cdata.stats_info_map());
+        for plot in self.plots() {
+            for chart in plot.charts(&self.chart_style) {
+                report.push_chart(&chart);
+            }
+        }
+        if !self.baseline.is_empty() {
+            report.set_regression_report(RegressionCheck::new().check(&self.cdata, &self.baseline));
+        }
+        report
+    }
+
+    /// A JUnit report with a test case for every function checked against
+    /// the baseline run if one was loaded and for every budget
+    pub fn junit_report(&self, name: &str) -> JUnitReport {
+        let mut report = JUnitReport::new(name);
+        if !self.baseline.is_empty() {
+            report
+                .add_regression_report(&RegressionCheck::new().check(&self.cdata, &self.baseline));
+        }
+        if !self.budgets.is_empty() {
+            report.add_budget_report(&self.budget_report());
+        }
+        report
+    }
+
+    pub fn suite_summary(&self) -> SuiteSummary {
+        SuiteSummary::new(&self.cdata)
+    }
+
     pub fn plots(&self) -> Vec<CriterionPlot> {
         let groups = self.cdata.groups();
         let mut cplots = Vec::<CriterionPlot>::new();
@@ -80,7 +310,11 @@ impl CriterionPlots {
 
             cplot.set_si_map(self.cdata.series_info_map(Some(group)));
             cplot.set_tt_data(self.cdata.total_time_data(Some(group)));
-            cplot.set_mt_data(self.cdata.mean_time_data(Some(group)));
+            cplot.set_mt_data(self.mean_time_data(Some(group)));
+            cplot.set_cmp_map(self.cdata.comparison_map(Some(group)));
+            cplot.set_cx_map(self.cdata.complexity_map(Some(group)));
+            cplot.set_cx_data(self.cdata.complexity_data(Some(group)));
+            cplot.set_budget_map(Some(self.budgets.budget_map(&self.cdata, Some(group))));
 
             cplots.push(cplot);
         }
@@ -109,6 +343,22 @@ impl ToTsxFile for CriterionPlot {
             tt_data.to_tsx_file(tsxfile)?;
         }
 
+        if let Some(cmp_map) = &self.comparison_map {
+            cmp_map.to_tsx_file(tsxfile)?;
+        }
+
+        if let Some(cx_map) = &self.complexity_map {
+            cx_map.to_tsx_file(tsxfile)?;
+        }
+
+        if let Some(cx_data) = &self.complexity_data {
+            cx_data.to_tsx_file(tsxfile)?;
+        }
+
+        if let Some(budget_map) = &self.budget_map {
+            budget_map.to_tsx_file(tsxfile)?;
+        }
+
         Ok(())
     }
 }
@@ -121,7 +371,7 @@ impl ToTsxFile for CriterionPlots {
             si_map.to_tsx_file(tsxfile)?;
         }
 
-        let mean_time_data = self.cdata.mean_time_data(None);
+        let mean_time_data = self.mean_time_data(None);
         if let Some(mt_data) = mean_time_data {
             mt_data.to_tsx_file(tsxfile)?;
         }
@@ -131,6 +381,22 @@ impl ToTsxFile for CriterionPlots {
             tt_data.to_tsx_file(tsxfile)?;
         }
 
+        if let Some(cmp_map) = self.cdata.comparison_map(None) {
+            cmp_map.to_tsx_file(tsxfile)?;
+        }
+
+        if let Some(cx_map) = self.cdata.complexity_map(None) {
+            cx_map.to_tsx_file(tsxfile)?;
+        }
+
+        if let Some(cx_data) = self.cdata.complexity_data(None) {
+            cx_data.to_tsx_file(tsxfile)?;
+        }
+
+        self.budgets
+            .budget_map(&self.cdata, None)
+            .to_tsx_file(tsxfile)?;
+
         Ok(())
     }
 }
@@ -0,0 +1,231 @@
+use crate::chart::Chart;
+use crate::infomap::StatsInfoMap;
+use crate::regression::{RegressionReport, Verdict};
+use crate::svg::SvgChart;
+use crate::timeunit::TimeUnit;
+use crate::xml::escape;
+use crate::GroupName;
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+const HTML_STYLE: &str =
+    "body { font-family: sans-serif; margin: 2em auto; max-width: 1320px; color: #222; }
+nav ul { columns: 4; }
+table { border-collapse: collapse; margin: 1em 0; }
+th, td { border: 1px solid #ddd; padding: 4px 10px; }
+th { background: #f5f5f5; }
+td.num { text-align: right; font-variant-numeric: tabular-nums; }
+td.regression { color: #b00020; }
+.charts { display: flex; flex-wrap: wrap; gap: 1em; }
+.charts svg { max-width: 100%; height: auto; }";
+
+/// A single HTML page with an index of the groups and, for every group, a
+/// table of stats and the charts inlined as SVG. The page loads nothing
+/// from the network.
+pub struct HtmlReport {
+    title: String,
+    stats_info_map: StatsInfoMap,
+    charts: BTreeMap<GroupName, Vec<String>>,
+    regression_report: Option<RegressionReport>,
+}
+
+impl HtmlReport {
+    /// The stats must be in nanoseconds
+    pub fn new(title: &str, stats_info_map: StatsInfoMap) -> HtmlReport {
+        HtmlReport {
+            title: title.to_string(),
+            stats_info_map,
+            charts: BTreeMap::<GroupName, Vec<String>>::new(),
+            regression_report: None,
+        }
+    }
+
+    /// Renders the chart as SVG and adds it to the section of its group
+    pub fn push_chart(&mut self, chart: &Chart) {
+        self.charts
+            .entry(chart.group().to_string())
+            .or_default()
+            .push(SvgChart::new(chart).to_svg());
+    }
+
+    pub fn set_regression_report(&mut self, regression_report: RegressionReport) {
+        self.regression_report = Some(regression_report);
+    }
+
+    pub fn write(&self, file_path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut htmlfile = File::create(file_path)?;
+        write!(htmlfile, "{}", self)?;
+        Ok(())
+    }
+
+    /// Every group with stats or charts in name order
+    fn groups(&self) -> Vec<&GroupName> {
+        let mut groups = self.stats_info_map.groups();
+        for group in self.charts.keys() {
+            if !groups.contains(&group) {
+                groups.push(group);
+            }
+        }
+        groups.sort();
+        groups
+    }
+
+    fn write_stats_table(&self, f: &mut fmt::Formatter<'_>, group: &str) -> fmt::Result {
+        let fn_map = match self.stats_info_map.get(group) {
+            Some(fn_map) => fn_map,
+            None => return Ok(()),
+        };
+        writeln!(f, "<table>")?;
+        write!(
+            f,
+            "<tr><th>Function</th><th>Mean</th><th>Median</th><th>Std Dev</th><th>95% CI</th>"
+        )?;
+        if self.regression_report.is_some() {
+            write!(f, "<th>Change</th>")?;
+        }
+        writeln!(f, "</tr>")?;
+        for (fn_name, stats_info) in fn_map {
+            let unit = TimeUnit::for_nanos(stats_info.mean());
+            let unit_nanos = unit.nanos();
+            write!(
+                f,
+                "<tr><td>{}</td><td class=\"num\">{:.2} {unit}</td><td class=\"num\">{:.2} {unit}</td><td class=\"num\">{:.2} {unit}</td><td class=\"num\">{:.2} – {:.2} {unit}</td>",
+                escape(fn_name),
+                stats_info.mean() / unit_nanos,
+                stats_info.median() / unit_nanos,
+                stats_info.std_dev() / unit_nanos,
+                stats_info.ci_lower() / unit_nanos,
+                stats_info.ci_upper() / unit_nanos,
+                unit = unit
+            )?;
+            if let Some(regression_report) = &self.regression_report {
+                match regression_report.get(group, fn_name) {
+                    Some(result) => write!(
+                        f,
+                        "<td class=\"num{}\" title=\"{}\">{:+.1}%</td>",
+                        match result.verdict() {
+                            Verdict::Regression => " regression",
+                            _ => "",
+                        },
+                        escape(&result.to_string()),
+                        result.change() * 100.0
+                    )?,
+                    None => write!(f, "<td class=\"num\">new</td>")?,
+                }
+            }
+            writeln!(f, "</tr>")?;
+        }
+        writeln!(f, "</table>")
+    }
+}
+
+/// An HTML id for the section of a group
+fn section_id(group: &str) -> String {
+    let id: String = group
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    format!("group-{}", id)
+}
+
+impl Display for HtmlReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups = self.groups();
+        writeln!(f, "<!DOCTYPE html>")?;
+        writeln!(f, "<html lang=\"en\">")?;
+        writeln!(f, "<head>")?;
+        writeln!(f, "<meta charset=\"utf-8\">")?;
+        writeln!(f, "<title>{}</title>", escape(&self.title))?;
+        writeln!(f, "<style>\n{}\n</style>", HTML_STYLE)?;
+        writeln!(f, "</head>")?;
+        writeln!(f, "<body>")?;
+        writeln!(f, "<h1>{}</h1>", escape(&self.title))?;
+
+        writeln!(f, "<nav>")?;
+        writeln!(f, "<ul>")?;
+        for group in &groups {
+            writeln!(
+                f,
+                "<li><a href=\"#{}\">{}</a></li>",
+                section_id(group),
+                escape(group)
+            )?;
+        }
+        writeln!(f, "</ul>")?;
+        writeln!(f, "</nav>")?;
+
+        for group in &groups {
+            writeln!(f, "<section id=\"{}\">", section_id(group))?;
+            writeln!(f, "<h2>{}</h2>", escape(group))?;
+            self.write_stats_table(f, group)?;
+            if let Some(charts) = self.charts.get(*group) {
+                writeln!(f, "<div class=\"charts\">")?;
+                for svg in charts {
+                    write!(f, "{}", svg)?;
+                }
+                writeln!(f, "</div>")?;
+            }
+            writeln!(f, "</section>")?;
+        }
+        writeln!(f, "</body>")?;
+        writeln!(f, "</html>")
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::chart::ChartStyle;
+    use crate::rawdata::CriterionData;
+    use crate::regression::RegressionCheck;
+    use crate::timeunit::TimeUnit;
+    use crate::tsxdata::TsxData;
+
+    #[test]
+    fn test_html_report() {
+        let mut baseline = CriterionData::new();
+        let mut current = CriterionData::new();
+        let insert = |cdata: &mut CriterionData, group: &str, function: &str| {
+            for (i, measurement) in [1000.0, 2100.0, 2900.0, 4000.0].iter().enumerate() {
+                cdata.insert(
+                    group.to_string(),
+                    function.to_string(),
+                    *measurement,
+                    (i + 1) as u32,
+                    TimeUnit::NS,
+                    None,
+                );
+            }
+        };
+        insert(&mut baseline, "Push", "a");
+        insert(&mut current, "Push", "a");
+        insert(&mut current, "Push", "<b>");
+        insert(&mut current, "Pop Front", "a");
+
+        let mut report = HtmlReport::new("Benchmarks & Co", current.stats_info_map());
+        let mt_data = current.mean_time_data(None).unwrap();
+        report.push_chart(&Chart::mean_time(
+            "push",
+            mt_data.get("push").unwrap(),
+            &ChartStyle::new(),
+        ));
+        report.set_regression_report(RegressionCheck::new().check(&current, &baseline));
+        let html = report.to_string();
+
+        assert!(html.starts_with("<!DOCTYPE html>\n"));
+        assert!(html.contains("<title>Benchmarks &amp; Co</title>"));
+        assert!(!html.contains("<script") && !html.contains("<link") && !html.contains("src="));
+        assert!(html.contains("<li><a href=\"#group-pop-front\">pop front</a></li>"));
+        assert!(html.contains("<li><a href=\"#group-push\">push</a></li>"));
+        assert!(html.contains("<td>&lt;b&gt;</td>"));
+        assert!(html.contains("<td class=\"num\">new</td>"));
+        assert_eq!(1, html.matches("<svg ").count());
+        assert!(html.contains("clip-path=\"url(#push-mt-plot-area)\""));
+    }
+}
@@ -1,5 +1,13 @@
 use std::collections::BTreeMap;
 
+use crate::budgets::Budget;
+use crate::json::json_number;
+use crate::stats::BoxPlot;
+use crate::stats::Comparison;
+use crate::stats::ComplexityFit;
+use crate::stats::KdeDataSet;
+use crate::stats::SuiteScore;
+use crate::stats::Summary;
 use crate::tsxdata::ToTsxFile;
 use crate::FnName;
 use crate::GroupName;
@@ -15,11 +23,28 @@ pub struct SeriesInfo {
     y_index: String,
     ty_index: String,
     dy_index: String,
+    fy_index: String,
+    hy_index: String,
+    cy_index: String,
+    ry_index: String,
+    my_index: String,
+    ly_index: String,
+    uy_index: String,
+    qy_index: String,
+    qry_index: String,
+    ey_index: String,
+    bmy_index: String,
+    bly_index: String,
+    buy_index: String,
 }
 
 pub struct StatsInfo {
     mean: f64,
+    median: f64,
     std_dev: f64,
+    ci_lower: f64,
+    ci_upper: f64,
+    warnings: Vec<String>,
 }
 
 pub struct InfoMap<T: ToTsxFile> {
@@ -29,8 +54,47 @@ pub struct InfoMap<T: ToTsxFile> {
 }
 
 impl StatsInfo {
-    pub fn new(mean: f64, std_dev: f64) -> StatsInfo {
-        StatsInfo { mean, std_dev }
+    pub fn new(
+        mean: f64,
+        median: f64,
+        std_dev: f64,
+        (ci_lower, ci_upper): (f64, f64),
+        warnings: Vec<String>,
+    ) -> StatsInfo {
+        StatsInfo {
+            mean,
+            median,
+            std_dev,
+            ci_lower,
+            ci_upper,
+            warnings,
+        }
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    pub fn median(&self) -> f64 {
+        self.median
+    }
+
+    pub fn std_dev(&self) -> f64 {
+        self.std_dev
+    }
+
+    /// The lower bound of the 95% confidence interval of the mean
+    pub fn ci_lower(&self) -> f64 {
+        self.ci_lower
+    }
+
+    /// The upper bound of the 95% confidence interval of the mean
+    pub fn ci_upper(&self) -> f64 {
+        self.ci_upper
+    }
+
+    pub fn warnings(&self) -> &Vec<String> {
+        &self.warnings
     }
 }
 
@@ -40,6 +104,19 @@ impl SeriesInfo {
             y_index: format!("y{}", y_index),
             ty_index: format!("tl{}", y_index),
             dy_index: format!("d{}", y_index),
+            fy_index: format!("f{}", y_index),
+            hy_index: format!("h{}", y_index),
+            cy_index: format!("c{}", y_index),
+            ry_index: format!("rm{}", y_index),
+            my_index: format!("cm{}", y_index),
+            ly_index: format!("cl{}", y_index),
+            uy_index: format!("cu{}", y_index),
+            qy_index: format!("q{}", y_index),
+            qry_index: format!("qr{}", y_index),
+            ey_index: format!("e{}", y_index),
+            bmy_index: format!("bm{}", y_index),
+            bly_index: format!("bl{}", y_index),
+            buy_index: format!("bu{}", y_index),
         }
     }
 }
@@ -48,8 +125,23 @@ impl Display for SeriesInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{{ yIndex: \"{}\", tyIndex: \"{}\", dyIndex: \"{}\" }}",
-            self.y_index, self.ty_index, self.dy_index
+            "{{ yIndex: \"{}\", tyIndex: \"{}\", dyIndex: \"{}\", fyIndex: \"{}\", hyIndex: \"{}\", cyIndex: \"{}\", ryIndex: \"{}\", myIndex: \"{}\", lyIndex: \"{}\", uyIndex: \"{}\", qyIndex: \"{}\", qryIndex: \"{}\", eyIndex: \"{}\", bmyIndex: \"{}\", blyIndex: \"{}\", buyIndex: \"{}\" }}",
+            self.y_index,
+            self.ty_index,
+            self.dy_index,
+            self.fy_index,
+            self.hy_index,
+            self.cy_index,
+            self.ry_index,
+            self.my_index,
+            self.ly_index,
+            self.uy_index,
+            self.qy_index,
+            self.qry_index,
+            self.ey_index,
+            self.bmy_index,
+            self.bly_index,
+            self.buy_index
         )
     }
 }
@@ -63,6 +155,14 @@ impl<T: ToTsxFile> InfoMap<T> {
         }
     }
 
+    fn groups(&self) -> Vec<&GroupName> {
+        self.info_map.keys().collect()
+    }
+
+    fn get(&self, group: &str) -> Option<&BTreeMap<FnName, T>> {
+        self.info_map.get(&group.to_ascii_lowercase())
+    }
+
     fn push(&mut self, group: &str, function: &str, val: T) {
         if let Some(fn_map) = self.info_map.get_mut(group) {
             fn_map.insert(function.to_ascii_lowercase(), val);
@@ -106,6 +206,19 @@ pub struct SeriesInfoMap {
     imap: InfoMap<SeriesInfo>,
 }
 
+/// Separates the two function names in the keys of the COMPARISON_MAP
+pub const COMPARISON_KEY_SEPARATOR: &str = " vs ";
+
+pub struct ComparisonMap {
+    cmap: InfoMap<Comparison>,
+}
+
+impl Default for StatsInfoMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl StatsInfoMap {
     pub fn new() -> StatsInfoMap {
         StatsInfoMap {
@@ -116,6 +229,22 @@ impl StatsInfoMap {
     pub fn push(&mut self, group: &str, function: &str, stats_info: StatsInfo) {
         self.smap.push(group, function, stats_info);
     }
+
+    pub fn groups(&self) -> Vec<&GroupName> {
+        self.smap.groups()
+    }
+
+    /// The stats of every function in a group keyed by the lowercase
+    /// function name
+    pub fn get(&self, group: &str) -> Option<&BTreeMap<FnName, StatsInfo>> {
+        self.smap.get(group)
+    }
+}
+
+impl Default for SeriesInfoMap {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SeriesInfoMap {
@@ -130,6 +259,230 @@ impl SeriesInfoMap {
     }
 }
 
+impl Default for ComparisonMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ComparisonMap {
+    pub fn new() -> ComparisonMap {
+        ComparisonMap {
+            cmap: InfoMap::new("COMPARISON_MAP", "Comparison"),
+        }
+    }
+
+    pub fn push(&mut self, group: &str, comparison: Comparison) {
+        let key = format!(
+            "{}{}{}",
+            comparison.fn_a(),
+            COMPARISON_KEY_SEPARATOR,
+            comparison.fn_b()
+        );
+        self.cmap.push(group, &key, comparison);
+    }
+}
+
+impl ToTsxFile for ComparisonMap {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        self.cmap.to_tsx_file(tsxfile)
+    }
+}
+
+pub struct ComplexityMap {
+    cmap: InfoMap<ComplexityFit>,
+}
+
+impl Default for ComplexityMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ComplexityMap {
+    pub fn new() -> ComplexityMap {
+        ComplexityMap {
+            cmap: InfoMap::new("COMPLEXITY_MAP", "ComplexityInfo"),
+        }
+    }
+
+    pub fn push(&mut self, group: &str, function: &str, fit: ComplexityFit) {
+        self.cmap.push(group, function, fit);
+    }
+}
+
+impl ToTsxFile for ComplexityMap {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        self.cmap.to_tsx_file(tsxfile)
+    }
+}
+
+pub struct BoxPlotMap {
+    bmap: InfoMap<BoxPlot>,
+}
+
+impl Default for BoxPlotMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BoxPlotMap {
+    pub fn new() -> BoxPlotMap {
+        BoxPlotMap {
+            bmap: InfoMap::new("BOX_PLOT_MAP", "BoxPlotInfo"),
+        }
+    }
+
+    pub fn push(&mut self, group: &str, function: &str, box_plot: BoxPlot) {
+        self.bmap.push(group, function, box_plot);
+    }
+}
+
+impl ToTsxFile for BoxPlotMap {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        self.bmap.to_tsx_file(tsxfile)
+    }
+}
+
+/// The mean time of a function relative to the baseline function of its
+/// group
+pub struct Relative {
+    comparison: Comparison,
+}
+
+impl Relative {
+    pub fn new(comparison: Comparison) -> Relative {
+        Relative { comparison }
+    }
+
+    pub fn baseline(&self) -> &String {
+        self.comparison.fn_b()
+    }
+
+    pub fn comparison(&self) -> &Comparison {
+        &self.comparison
+    }
+}
+
+pub struct RelativeMap {
+    rmap: InfoMap<Relative>,
+}
+
+impl Default for RelativeMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelativeMap {
+    pub fn new() -> RelativeMap {
+        RelativeMap {
+            rmap: InfoMap::new("RELATIVE_MAP", "RelativeInfo"),
+        }
+    }
+
+    pub fn push(&mut self, group: &str, function: &str, relative: Relative) {
+        self.rmap.push(group, function, relative);
+    }
+
+    /// The relative times of every function in a group keyed by the
+    /// lowercase function name
+    pub fn get(&self, group: &str) -> Option<&BTreeMap<FnName, Relative>> {
+        self.rmap.get(group)
+    }
+}
+
+impl ToTsxFile for RelativeMap {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        self.rmap.to_tsx_file(tsxfile)
+    }
+}
+
+pub struct SummaryMap {
+    smap: InfoMap<Summary>,
+}
+
+impl Default for SummaryMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SummaryMap {
+    pub fn new() -> SummaryMap {
+        SummaryMap {
+            smap: InfoMap::new("SUMMARY_MAP", "SummaryInfo"),
+        }
+    }
+
+    pub fn push(&mut self, group: &str, function: &str, summary: Summary) {
+        self.smap.push(group, function, summary);
+    }
+}
+
+impl ToTsxFile for SummaryMap {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        self.smap.to_tsx_file(tsxfile)
+    }
+}
+
+pub struct BudgetMap {
+    bmap: InfoMap<Vec<Budget>>,
+}
+
+impl Default for BudgetMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BudgetMap {
+    pub fn new() -> BudgetMap {
+        BudgetMap {
+            bmap: InfoMap::new("BUDGET_MAP", "BudgetsInfo"),
+        }
+    }
+
+    pub fn push(&mut self, group: &str, function: &str, budgets: Vec<Budget>) {
+        self.bmap.push(group, function, budgets);
+    }
+}
+
+impl ToTsxFile for BudgetMap {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        self.bmap.to_tsx_file(tsxfile)
+    }
+}
+
+pub struct ViolinMap {
+    vmap: InfoMap<KdeDataSet>,
+}
+
+impl Default for ViolinMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ViolinMap {
+    pub fn new() -> ViolinMap {
+        ViolinMap {
+            vmap: InfoMap::new("VIOLIN_MAP", "ViolinInfo"),
+        }
+    }
+
+    pub fn push(&mut self, group: &str, function: &str, kde_data: KdeDataSet) {
+        self.vmap.push(group, function, kde_data);
+    }
+}
+
+impl ToTsxFile for ViolinMap {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        self.vmap.to_tsx_file(tsxfile)
+    }
+}
+
 impl ToTsxFile for SeriesInfoMap {
     fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
         self.imap.to_tsx_file(tsxfile)
@@ -144,7 +497,15 @@ impl ToTsxFile for StatsInfoMap {
 
 impl ToTsxFile for StatsInfo {
     fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
-        write!(tsxfile, "{{mean:{},stdDev:{}}}", self.mean, self.std_dev)?;
+        write!(
+            tsxfile,
+            "{{mean:{},median:{},stdDev:{},ciLower:{},ciUpper:{},warnings:[",
+            self.mean, self.median, self.std_dev, self.ci_lower, self.ci_upper
+        )?;
+        for warning in &self.warnings {
+            write!(tsxfile, "{:?},", warning)?;
+        }
+        write!(tsxfile, "]}}")?;
         Ok(())
     }
 }
@@ -153,8 +514,168 @@ impl ToTsxFile for SeriesInfo {
     fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
         write!(
             tsxfile,
-            "{{yIndex:\"{}\",tyIndex:\"{}\",dyIndex:\"{}\"}}",
-            self.y_index, self.ty_index, self.dy_index
+            "{{yIndex:\"{}\",tyIndex:\"{}\",dyIndex:\"{}\",fyIndex:\"{}\",hyIndex:\"{}\",cyIndex:\"{}\",ryIndex:\"{}\",myIndex:\"{}\",lyIndex:\"{}\",uyIndex:\"{}\",qyIndex:\"{}\",qryIndex:\"{}\",eyIndex:\"{}\",bmyIndex:\"{}\",blyIndex:\"{}\",buyIndex:\"{}\"}}",
+            self.y_index,
+            self.ty_index,
+            self.dy_index,
+            self.fy_index,
+            self.hy_index,
+            self.cy_index,
+            self.ry_index,
+            self.my_index,
+            self.ly_index,
+            self.uy_index,
+            self.qy_index,
+            self.qry_index,
+            self.ey_index,
+            self.bmy_index,
+            self.bly_index,
+            self.buy_index
+        )?;
+        Ok(())
+    }
+}
+
+impl ToTsxFile for Comparison {
+    /// Non-finite values e.g. the ratio to a zero mean are written as null
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        let p_value = |p: f64| match p.is_finite() {
+            true => format!("{:e}", p),
+            false => json_number(p),
+        };
+        write!(
+            tsxfile,
+            "{{ratio:{},ciLower:{},ciUpper:{},tPValue:{},uPValue:{},effectSize:{}}}",
+            json_number(self.ratio()),
+            json_number(self.ci_lower()),
+            json_number(self.ci_upper()),
+            p_value(self.t_p_value()),
+            p_value(self.u_p_value()),
+            json_number(self.effect_size())
+        )?;
+        Ok(())
+    }
+}
+
+impl ToTsxFile for ComplexityFit {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        write!(
+            tsxfile,
+            "{{model:\"{}\",coefficient:{},rms:{},rSquared:{}}}",
+            self.complexity(),
+            self.coefficient(),
+            self.rms(),
+            self.r_squared()
+        )?;
+        Ok(())
+    }
+}
+
+impl ToTsxFile for Relative {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        write!(
+            tsxfile,
+            "{{baseline:{:?},ratio:{},ciLower:{},ciUpper:{}}}",
+            self.baseline(),
+            json_number(self.comparison.ratio()),
+            json_number(self.comparison.ci_lower()),
+            json_number(self.comparison.ci_upper())
+        )?;
+        Ok(())
+    }
+}
+
+impl ToTsxFile for Summary {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        write!(
+            tsxfile,
+            "{{mean:{},meanCiLower:{},meanCiUpper:{},median:{},medianCiLower:{},medianCiUpper:{}}}",
+            self.mean(),
+            self.mean_ci_lower(),
+            self.mean_ci_upper(),
+            self.median(),
+            self.median_ci_lower(),
+            self.median_ci_upper()
+        )?;
+        Ok(())
+    }
+}
+
+impl ToTsxFile for SuiteScore {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        write!(
+            tsxfile,
+            "{{numerator:{:?},denominator:{:?},pairs:{},geomean:{},ciLower:{},ciUpper:{}}}",
+            self.numerator(),
+            self.denominator(),
+            self.pairs(),
+            json_number(self.geomean()),
+            json_number(self.ci_lower()),
+            json_number(self.ci_upper())
+        )?;
+        Ok(())
+    }
+}
+
+impl ToTsxFile for Budget {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        write!(
+            tsxfile,
+            "{{statistic:\"{}\",operator:\"{}\",limit:{}}}",
+            self.statistic(),
+            self.operator(),
+            self.limit_nanos()
+        )?;
+        Ok(())
+    }
+}
+
+impl ToTsxFile for Vec<Budget> {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        write!(tsxfile, "[")?;
+        for budget in self {
+            budget.to_tsx_file(tsxfile)?;
+            write!(tsxfile, ",")?;
+        }
+        write!(tsxfile, "]")?;
+        Ok(())
+    }
+}
+
+impl ToTsxFile for BoxPlot {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        let outliers: Vec<String> = self.outliers().iter().map(|x| x.to_string()).collect();
+        write!(
+            tsxfile,
+            "{{q1:{},median:{},q3:{},lowerWhisker:{},upperWhisker:{},outliers:[{}]}}",
+            self.q1(),
+            self.median(),
+            self.q3(),
+            self.lower_whisker(),
+            self.upper_whisker(),
+            outliers.join(",")
+        )?;
+        Ok(())
+    }
+}
+
+impl ToTsxFile for KdeDataSet {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        let x_vals: Vec<String> = self
+            .points()
+            .iter()
+            .map(|p| p.x_val().to_string())
+            .collect();
+        let densities: Vec<String> = self
+            .points()
+            .iter()
+            .map(|p| p.density().to_string())
+            .collect();
+        write!(
+            tsxfile,
+            "{{x:[{}],density:[{}]}}",
+            x_vals.join(","),
+            densities.join(",")
         )?;
         Ok(())
     }
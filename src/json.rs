@@ -0,0 +1,31 @@
+use std::fmt::Write;
+
+/// A JSON number or null if the value is not finite
+pub(crate) fn json_number(val: f64) -> String {
+    if val.is_finite() {
+        val.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+/// A quoted JSON string
+pub(crate) fn json_string(val: &str) -> String {
+    let mut json = String::with_capacity(val.len() + 2);
+    json.push('"');
+    for c in val.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
@@ -0,0 +1,221 @@
+use crate::budgets::BudgetReport;
+use crate::regression::{RegressionReport, Verdict};
+use crate::xml::escape;
+
+use std::error::Error;
+use std::fmt::Write as FmtWrite;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// A JUnit test case. A failure has a short message and a longer
+/// description.
+struct TestCase {
+    classname: String,
+    name: String,
+    failure: Option<(String, String)>,
+}
+
+/// A JUnit test suite
+struct TestSuite {
+    name: String,
+    test_cases: Vec<TestCase>,
+}
+
+/// A JUnit XML report with one test case for every function checked
+/// against a baseline run and one for every budget of a function
+pub struct JUnitReport {
+    name: String,
+    test_suites: Vec<TestSuite>,
+}
+
+impl JUnitReport {
+    pub fn new(name: &str) -> JUnitReport {
+        JUnitReport {
+            name: name.to_string(),
+            test_suites: Vec::<TestSuite>::new(),
+        }
+    }
+
+    /// Adds a test suite with a test case for every function of the report.
+    /// Regressions are failures.
+    pub fn add_regression_report(&mut self, regression_report: &RegressionReport) {
+        let test_cases = regression_report
+            .results()
+            .iter()
+            .map(|result| TestCase {
+                classname: result.group().to_string(),
+                name: result.function().to_string(),
+                failure: match result.verdict() {
+                    Verdict::Regression => Some((
+                        format!(
+                            "mean changed {:+.1}% (threshold {:.1}%)",
+                            result.change() * 100.0,
+                            result.threshold() * 100.0
+                        ),
+                        result.to_string(),
+                    )),
+                    _ => None,
+                },
+            })
+            .collect();
+        self.test_suites.push(TestSuite {
+            name: "regressions".to_string(),
+            test_cases,
+        });
+    }
+
+    /// Adds a test suite with a test case for every budget of every
+    /// function. Exceeded budgets are failures.
+    pub fn add_budget_report(&mut self, budget_report: &BudgetReport) {
+        let test_cases = budget_report
+            .results()
+            .iter()
+            .map(|result| TestCase {
+                classname: result.group().to_string(),
+                name: format!("{}: {}", result.function(), result.budget()),
+                failure: if result.passed() {
+                    None
+                } else {
+                    Some((
+                        format!(
+                            "{} {:.3} {} exceeds the budget {}",
+                            result.budget().statistic(),
+                            result.measured_in_budget_unit(),
+                            result.budget().unit(),
+                            result.budget()
+                        ),
+                        result.to_string(),
+                    ))
+                },
+            })
+            .collect();
+        self.test_suites.push(TestSuite {
+            name: "budgets".to_string(),
+            test_cases,
+        });
+    }
+
+    pub fn to_xml(&self) -> String {
+        let tests: usize = self.test_suites.iter().map(|s| s.test_cases.len()).sum();
+        let failures: usize = self.test_suites.iter().map(|s| s.failures()).sum();
+
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\">",
+            escape(&self.name),
+            tests,
+            failures
+        );
+        for test_suite in &self.test_suites {
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\">",
+                escape(&test_suite.name),
+                test_suite.test_cases.len(),
+                test_suite.failures()
+            );
+            for test_case in &test_suite.test_cases {
+                let _ = write!(
+                    xml,
+                    "    <testcase classname=\"{}\" name=\"{}\"",
+                    escape(&test_case.classname),
+                    escape(&test_case.name)
+                );
+                match &test_case.failure {
+                    None => xml.push_str("/>\n"),
+                    Some((message, description)) => {
+                        xml.push_str(">\n");
+                        let _ = writeln!(
+                            xml,
+                            "      <failure message=\"{}\">{}</failure>",
+                            escape(message),
+                            escape(description)
+                        );
+                        xml.push_str("    </testcase>\n");
+                    }
+                }
+            }
+            xml.push_str("  </testsuite>\n");
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    pub fn write(&self, file_path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut xmlfile = File::create(file_path)?;
+        write!(xmlfile, "{}", self.to_xml())?;
+        Ok(())
+    }
+}
+
+impl TestSuite {
+    fn failures(&self) -> usize {
+        self.test_cases
+            .iter()
+            .filter(|test_case| test_case.failure.is_some())
+            .count()
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::budgets::Budgets;
+    use crate::rawdata::CriterionData;
+    use crate::regression::RegressionCheck;
+    use crate::timeunit::TimeUnit;
+
+    fn insert(cdata: &mut CriterionData, function: &str, scale: f64) {
+        for measurement in [10.0, 10.2, 9.8, 10.1, 9.9, 10.0, 10.3, 9.7] {
+            cdata.insert(
+                "Push".to_string(),
+                function.to_string(),
+                measurement * scale,
+                1,
+                TimeUnit::NS,
+                None,
+            );
+        }
+    }
+
+    #[test]
+    fn test_junit_report() {
+        let mut baseline = CriterionData::new();
+        insert(&mut baseline, "a", 1.0);
+        insert(&mut baseline, "b<T>", 1.0);
+        let mut current = CriterionData::new();
+        insert(&mut current, "a", 1.0);
+        insert(&mut current, "b<T>", 1.5);
+        let budgets = "[push]\n\"*\" = \"mean < 12 ns\""
+            .parse::<Budgets>()
+            .unwrap();
+
+        let mut report = JUnitReport::new("benchmarks");
+        report.add_regression_report(&RegressionCheck::new().check(&current, &baseline));
+        report.add_budget_report(&budgets.evaluate(&current));
+        let xml = report.to_xml();
+
+        assert!(xml.contains("<testsuites name=\"benchmarks\" tests=\"4\" failures=\"2\">"));
+        assert!(xml.contains("<testcase classname=\"push\" name=\"a\"/>"));
+        assert!(xml.contains(
+            "<testcase classname=\"push\" name=\"b&lt;t&gt;\">\n      <failure message=\"mean changed +50.0% (threshold 5.0%)\">"
+        ));
+        assert!(xml.contains("<testcase classname=\"push\" name=\"a: mean &lt; 12 ns\"/>"));
+        assert!(xml.contains("name=\"b&lt;t&gt;: mean &lt; 12 ns\">"));
+        assert!(
+            xml.contains("<failure message=\"mean 15.000 ns exceeds the budget mean &lt; 12 ns\">")
+        );
+
+        let budgets = "[push]\n\"*\" = \"mean < 0.012 us\""
+            .parse::<Budgets>()
+            .unwrap();
+        let mut report = JUnitReport::new("benchmarks");
+        report.add_budget_report(&budgets.evaluate(&current));
+        assert!(report
+            .to_xml()
+            .contains("<failure message=\"mean 0.015 µs exceeds the budget mean &lt; 0.012 µs\">"));
+    }
+}
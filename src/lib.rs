@@ -3,14 +3,29 @@ use std::fmt;
 use std::path::Path;
 use std::path::PathBuf;
 
+pub mod budgets;
 pub mod ccharts;
+pub mod chart;
 pub mod cplot;
+pub mod html;
 pub mod infomap;
+pub mod json;
+pub mod junit;
+pub mod markdown;
+pub mod meanband;
+pub mod pdfmode;
+pub mod plotly;
+pub mod plotscript;
 pub mod rawdata;
+pub mod regression;
 pub mod stats;
+pub mod summary;
+pub mod svg;
 pub mod timeunit;
 pub mod tsxcode;
 pub mod tsxdata;
+pub mod vegalite;
+pub mod xml;
 
 type GroupName = String;
 type FnName = String;
@@ -19,6 +34,8 @@ type Measure = f64;
 type YIndex = u16;
 type Density = f64;
 type KdeXVal = f64;
+type Param = f64;
+type SampleIndex = u32;
 
 const SAMPLE_SIZE: usize = 100;
 
@@ -0,0 +1,148 @@
+use crate::infomap::StatsInfoMap;
+use crate::regression::{RegressionReport, Verdict};
+use crate::timeunit::TimeUnit;
+
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// A Markdown summary of the mean time per iteration of every function with
+/// one table per group. When a regression report against a baseline run is
+/// set, each function also shows its change with a ✅ or a ⚠️ for a
+/// regression.
+pub struct MarkdownReport {
+    title: String,
+    stats_info_map: StatsInfoMap,
+    regression_report: Option<RegressionReport>,
+}
+
+impl MarkdownReport {
+    /// The stats must be in nanoseconds
+    pub fn new(title: &str, stats_info_map: StatsInfoMap) -> MarkdownReport {
+        MarkdownReport {
+            title: title.to_string(),
+            stats_info_map,
+            regression_report: None,
+        }
+    }
+
+    pub fn set_regression_report(&mut self, regression_report: RegressionReport) {
+        self.regression_report = Some(regression_report);
+    }
+
+    pub fn write(&self, file_path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut mdfile = File::create(file_path)?;
+        write!(mdfile, "{}", self)?;
+        Ok(())
+    }
+
+    fn change(&self, group: &str, function: &str) -> String {
+        let regression_report = match &self.regression_report {
+            Some(regression_report) => regression_report,
+            None => return String::new(),
+        };
+        match regression_report.get(group, function) {
+            Some(result) => format!(
+                "{:+.1}% {}",
+                result.change() * 100.0,
+                match result.verdict() {
+                    Verdict::Regression => "⚠️",
+                    _ => "✅",
+                }
+            ),
+            None => "new".to_string(),
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+impl Display for MarkdownReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# {}", self.title)?;
+        for group in self.stats_info_map.groups() {
+            let fn_map = match self.stats_info_map.get(group) {
+                Some(fn_map) => fn_map,
+                None => continue,
+            };
+            writeln!(f)?;
+            writeln!(f, "## {}", escape(group))?;
+            writeln!(f)?;
+            if self.regression_report.is_some() {
+                writeln!(
+                    f,
+                    "| Function | Mean | Median | Std Dev | 95% CI | Change |"
+                )?;
+                writeln!(f, "|---|---:|---:|---:|---:|---:|")?;
+            } else {
+                writeln!(f, "| Function | Mean | Median | Std Dev | 95% CI |")?;
+                writeln!(f, "|---|---:|---:|---:|---:|")?;
+            }
+            for (fn_name, stats_info) in fn_map {
+                let unit = TimeUnit::for_nanos(stats_info.mean());
+                let unit_nanos = unit.nanos();
+                write!(
+                    f,
+                    "| {} | {:.2} {unit} | {:.2} {unit} | {:.2} {unit} | {:.2} – {:.2} {unit} |",
+                    escape(fn_name),
+                    stats_info.mean() / unit_nanos,
+                    stats_info.median() / unit_nanos,
+                    stats_info.std_dev() / unit_nanos,
+                    stats_info.ci_lower() / unit_nanos,
+                    stats_info.ci_upper() / unit_nanos,
+                    unit = unit
+                )?;
+                if self.regression_report.is_some() {
+                    write!(f, " {} |", self.change(group, fn_name))?;
+                }
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::rawdata::CriterionData;
+    use crate::regression::RegressionCheck;
+    use crate::timeunit::TimeUnit;
+
+    #[test]
+    fn test_markdown_report() {
+        let mut baseline = CriterionData::new();
+        let mut current = CriterionData::new();
+        let insert = |cdata: &mut CriterionData, function: &str| {
+            for measurement in [1000.0, 1100.0, 1050.0, 950.0] {
+                cdata.insert(
+                    "Push".to_string(),
+                    function.to_string(),
+                    measurement,
+                    1,
+                    TimeUnit::NS,
+                    None,
+                );
+            }
+        };
+        insert(&mut baseline, "a");
+        insert(&mut current, "a");
+        insert(&mut current, "b|c");
+
+        let mut report = MarkdownReport::new("Benchmarks", current.stats_info_map());
+        assert!(!report.to_string().contains("Change"));
+
+        report.set_regression_report(RegressionCheck::new().check(&current, &baseline));
+        let markdown = report.to_string();
+        assert!(markdown.starts_with("# Benchmarks\n\n## push\n"));
+        assert!(markdown.contains("| a | 1.02 µs | 1.02 µs | 0.06 µs | 0.92 – 1.13 µs |"));
+        assert!(markdown.contains("+0.0% ✅ |"));
+        assert!(markdown.contains("| b\\|c |"));
+        assert!(markdown.contains(" new |"));
+    }
+}
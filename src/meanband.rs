@@ -0,0 +1,54 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Selects the band drawn around the mean in the mean time per iteration
+/// chart.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub enum MeanBand {
+    /// The confidence interval of the mean
+    #[default]
+    ConfidenceInterval,
+    /// The mean plus or minus k standard deviations
+    StdDev(f64),
+}
+
+#[derive(Debug, Clone)]
+pub struct ParseMeanBandError(String);
+
+impl Error for ParseMeanBandError {}
+
+impl Display for MeanBand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeanBand::ConfidenceInterval => write!(f, "ci"),
+            MeanBand::StdDev(k) => write!(f, "{}sd", k),
+        }
+    }
+}
+
+impl Display for ParseMeanBandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid mean band: {} (expected ci, sd or <k>sd e.g. 2sd)",
+            self.0
+        )
+    }
+}
+
+impl FromStr for MeanBand {
+    type Err = ParseMeanBandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ci" => Ok(Self::ConfidenceInterval),
+            "sd" => Ok(Self::StdDev(1.0)),
+            band => match band.strip_suffix("sd").map(|k| k.parse::<f64>()) {
+                Some(Ok(k)) if k > 0.0 => Ok(Self::StdDev(k)),
+                _ => Err(ParseMeanBandError(s.to_string())),
+            },
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::stats::BinRule;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Selects how the probability density of the mean time per iteration is
+/// rendered in the PDF chart.
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub enum PdfMode {
+    #[default]
+    Kde,
+    Histogram(BinRule),
+}
+
+#[derive(Debug, Clone)]
+pub struct ParsePdfModeError(String);
+
+impl Error for ParsePdfModeError {}
+
+impl Display for PdfMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PdfMode::Kde => write!(f, "kde"),
+            PdfMode::Histogram(BinRule::FreedmanDiaconis) => write!(f, "fd"),
+            PdfMode::Histogram(BinRule::Sturges) => write!(f, "sturges"),
+            PdfMode::Histogram(BinRule::Fixed(bins)) => write!(f, "{}", bins),
+        }
+    }
+}
+
+impl Display for ParsePdfModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid pdf mode: {} (expected kde, fd, sturges or a bin count)",
+            self.0
+        )
+    }
+}
+
+impl FromStr for PdfMode {
+    type Err = ParsePdfModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "kde" => Ok(Self::Kde),
+            "fd" | "freedman-diaconis" => Ok(Self::Histogram(BinRule::FreedmanDiaconis)),
+            "sturges" => Ok(Self::Histogram(BinRule::Sturges)),
+            bins => match bins.parse::<u16>() {
+                Ok(bins) if bins > 0 => Ok(Self::Histogram(BinRule::Fixed(bins))),
+                _ => Err(ParsePdfModeError(s.to_string())),
+            },
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::chart::{Chart, ChartKind, Mark};
+use crate::json::{json_number, json_string};
+
+use std::error::Error;
+use std::fmt::Write as FmtWrite;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+const PLOTLY_WIDTH: u32 = 640;
+const PLOTLY_HEIGHT: u32 = 400;
+
+/// A Plotly figure of a chart. Every layer of the chart is a trace and every
+/// rule is a vertical line shape. The axis titles are the same as those of
+/// the TSX components.
+pub struct PlotlyFigure<'a> {
+    chart: &'a Chart,
+}
+
+impl<'a> PlotlyFigure<'a> {
+    pub fn new(chart: &'a Chart) -> PlotlyFigure<'a> {
+        PlotlyFigure { chart }
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\n  \"data\": [");
+        for (i, layer) in self.chart.layers().iter().enumerate() {
+            json.push_str(if i == 0 { "\n    " } else { ",\n    " });
+            let (xs, ys): (Vec<String>, Vec<String>) = layer
+                .points()
+                .iter()
+                .map(|(x, y)| (json_number(*x), json_number(*y)))
+                .unzip();
+            let color = json_string(layer.color());
+            let style = match layer.mark() {
+                Mark::Points => format!(
+                    "\"mode\": \"markers\", \"marker\": {{\"color\": {}, \"size\": 4}}",
+                    color
+                ),
+                Mark::Line => format!(
+                    "\"mode\": \"lines\", \"line\": {{\"color\": {}, \"width\": 1}}",
+                    color
+                ),
+                Mark::Area => format!(
+                    "\"mode\": \"lines\", \"fill\": \"tozeroy\", \"line\": {{\"color\": {}, \"width\": 1}}",
+                    color
+                ),
+                Mark::StepArea => format!(
+                    "\"mode\": \"lines\", \"fill\": \"tozeroy\", \"line\": {{\"color\": {}, \"width\": 1, \"shape\": \"hv\"}}",
+                    color
+                ),
+            };
+            let _ = write!(
+                json,
+                "{{\"type\": \"scatter\", \"name\": {}, \"legendgroup\": {}, \"x\": [{}], \"y\": [{}], {}}}",
+                json_string(layer.name()),
+                json_string(layer.fn_name()),
+                xs.join(", "),
+                ys.join(", "),
+                style
+            );
+        }
+        json.push_str("\n  ],\n  \"layout\": {\n");
+        let _ = writeln!(
+            json,
+            "    \"title\": {{\"text\": {}}},",
+            json_string(&self.chart.title())
+        );
+        let _ = writeln!(json, "    \"width\": {},", PLOTLY_WIDTH);
+        let _ = writeln!(json, "    \"height\": {},", PLOTLY_HEIGHT);
+        let _ = writeln!(
+            json,
+            "    \"xaxis\": {{\"title\": {{\"text\": {}}}}},",
+            json_string(&self.chart.x_label())
+        );
+        let _ = writeln!(
+            json,
+            "    \"yaxis\": {{\"title\": {{\"text\": {}}}, \"rangemode\": {}}},",
+            json_string(&self.chart.y_label()),
+            match self.chart.kind() {
+                ChartKind::Pdf => "\"tozero\"",
+                _ => "\"normal\"",
+            }
+        );
+        json.push_str("    \"shapes\": [");
+        for (i, rule) in self.chart.rules().iter().enumerate() {
+            json.push_str(if i == 0 { "\n      " } else { ",\n      " });
+            let _ = write!(
+                json,
+                "{{\"type\": \"line\", \"name\": {}, \"xref\": \"x\", \"yref\": \"paper\", \"x0\": {x}, \"x1\": {x}, \"y0\": 0, \"y1\": 1, \"line\": {{\"color\": {}, \"width\": 1, \"dash\": \"dash\"}}}}",
+                json_string(rule.name()),
+                json_string(rule.color()),
+                x = json_number(rule.x())
+            );
+        }
+        if !self.chart.rules().is_empty() {
+            json.push_str("\n    ");
+        }
+        json.push_str("]\n  }\n}\n");
+        json
+    }
+
+    pub fn write(&self, file_path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut jsonfile = File::create(file_path)?;
+        write!(jsonfile, "{}", self.to_json())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::chart::ChartStyle;
+    use crate::rawdata::CriterionData;
+    use crate::timeunit::TimeUnit;
+    use crate::tsxdata::TsxData;
+
+    #[test]
+    fn test_plotly_figure() {
+        let mut cdata = CriterionData::new();
+        for (i, measurement) in [10.0, 20.5, 29.5, 40.0].iter().enumerate() {
+            cdata.insert(
+                "Push".to_string(),
+                "Vec".to_string(),
+                *measurement,
+                (i + 1) as u32,
+                TimeUnit::NS,
+                None,
+            );
+        }
+        let tt_data = cdata.total_time_data(None).unwrap();
+        let chart =
+            Chart::linear_regression("push", tt_data.get("push").unwrap(), &ChartStyle::new());
+
+        let json = PlotlyFigure::new(&chart).to_json();
+        assert!(json.contains(
+            "{\"type\": \"scatter\", \"name\": \"vec\", \"legendgroup\": \"vec\", \"x\": [1, 2, 3, 4], \"y\": [10, 20.5, 29.5, 40], \"mode\": \"markers\""
+        ));
+        assert!(json.contains("{\"type\": \"scatter\", \"name\": \"LR (vec)\""));
+        assert!(json.contains("\"xaxis\": {\"title\": {\"text\": \"Iterations\"}}"));
+        assert!(json.contains(
+            "\"yaxis\": {\"title\": {\"text\": \"Total Sample Time (ns)\"}, \"rangemode\": \"normal\"}"
+        ));
+        assert!(json.ends_with("\"shapes\": []\n  }\n}\n"));
+
+        let mt_data = cdata.mean_time_data(None).unwrap();
+        let dataset = mt_data.get("push").unwrap();
+        let pdf_data = mt_data.data().pdf_data("push").unwrap();
+        let chart = Chart::pdf("push", dataset, &pdf_data, &ChartStyle::new());
+        let json = PlotlyFigure::new(&chart).to_json();
+        assert!(json.contains("\"fill\": \"tozeroy\""));
+        assert!(json.contains(
+            "{\"type\": \"line\", \"name\": \"Mean (vec)\", \"xref\": \"x\", \"yref\": \"paper\", \"x0\": 10.02"
+        ));
+    }
+}
@@ -0,0 +1,325 @@
+use crate::chart::{Chart, ChartKind, Mark};
+
+use std::error::Error;
+use std::fmt::Write as FmtWrite;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// The plotting tool that a script is written for
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ScriptFormat {
+    /// A gnuplot script that renders the chart to a PDF
+    Gnuplot,
+    /// A pgfplots tikzpicture to \input into a LaTeX document
+    Pgfplots,
+}
+
+/// A chart as a data file and a script that plots it. Layer n of the chart
+/// is in columns 2n and 2n + 1 of the data file. Layers with fewer points
+/// are padded with nan, which both gnuplot and pgfplots skip.
+pub struct PlotScript<'a> {
+    chart: &'a Chart,
+    format: ScriptFormat,
+}
+
+impl ScriptFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ScriptFormat::Gnuplot => "gp",
+            ScriptFormat::Pgfplots => "tex",
+        }
+    }
+}
+
+impl<'a> PlotScript<'a> {
+    pub fn new(chart: &'a Chart, format: ScriptFormat) -> PlotScript<'a> {
+        PlotScript { chart, format }
+    }
+
+    pub fn to_dat(&self) -> String {
+        let layers = self.chart.layers();
+        let mut dat = String::new();
+        let _ = writeln!(dat, "# {}", self.chart.title());
+        let names: Vec<String> = layers
+            .iter()
+            .map(|layer| format!("\"{}\" (x y)", layer.name()))
+            .collect();
+        let _ = writeln!(dat, "# {}", names.join(" "));
+        let rows = layers
+            .iter()
+            .map(|layer| layer.points().len())
+            .max()
+            .unwrap_or(0);
+        for row in 0..rows {
+            let values: Vec<String> = layers
+                .iter()
+                .flat_map(|layer| match layer.points().get(row) {
+                    Some((x, y)) => [dat_number(*x), dat_number(*y)],
+                    None => ["nan".to_string(), "nan".to_string()],
+                })
+                .collect();
+            let _ = writeln!(dat, "{}", values.join(" "));
+        }
+        dat
+    }
+
+    /// The script that plots the data file named `dat_filename`
+    pub fn to_script(&self, dat_filename: &str) -> String {
+        match self.format {
+            ScriptFormat::Gnuplot => self.to_gnuplot(dat_filename),
+            ScriptFormat::Pgfplots => self.to_pgfplots(dat_filename),
+        }
+    }
+
+    /// Writes the script to `file_path` and the data to a .dat file next to
+    /// it
+    pub fn write(&self, file_path: &Path) -> Result<(), Box<dyn Error>> {
+        let dat_path = file_path.with_extension("dat");
+        let dat_filename = match dat_path.file_name().and_then(|name| name.to_str()) {
+            Some(dat_filename) => dat_filename.to_string(),
+            None => return Err(crate::InvalidPath::new(&dat_path).into()),
+        };
+        let mut datfile = File::create(&dat_path)?;
+        write!(datfile, "{}", self.to_dat())?;
+        let mut scriptfile = File::create(file_path)?;
+        write!(scriptfile, "{}", self.to_script(&dat_filename))?;
+        Ok(())
+    }
+
+    /// Run from the directory of the data file e.g. gnuplot push-mt.gp
+    fn to_gnuplot(&self, dat_filename: &str) -> String {
+        let pdf_filename = match dat_filename.strip_suffix(".dat") {
+            Some(basename) => format!("{}.pdf", basename),
+            None => format!("{}.pdf", dat_filename),
+        };
+        let mut gp = String::new();
+        gp.push_str("set terminal pdfcairo size 6in,3.75in font 'sans,10'\n");
+        gp.push_str("set termoption noenhanced\n");
+        let _ = writeln!(gp, "set output {}", gnuplot_string(&pdf_filename));
+        let _ = writeln!(gp, "set title {}", gnuplot_string(&self.chart.title()));
+        let _ = writeln!(gp, "set xlabel {}", gnuplot_string(&self.chart.x_label()));
+        let _ = writeln!(gp, "set ylabel {}", gnuplot_string(&self.chart.y_label()));
+        gp.push_str("set key outside right top\n");
+        gp.push_str("set grid\n");
+        if self.chart.kind() == ChartKind::Pdf {
+            gp.push_str("set yrange [0:*]\n");
+        }
+        for rule in self.chart.rules() {
+            let _ = writeln!(
+                gp,
+                "set arrow from {x}, graph 0 to {x}, graph 1 nohead dashtype 2 linecolor rgb {}",
+                gnuplot_string(rule.color()),
+                x = rule.x()
+            );
+        }
+        let plots: Vec<String> = self
+            .chart
+            .layers()
+            .iter()
+            .enumerate()
+            .map(|(i, layer)| {
+                let style = match layer.mark() {
+                    Mark::Points => "with points pointtype 7 pointsize 0.3",
+                    Mark::Line => "with lines linewidth 1",
+                    Mark::Area => "with filledcurves y=0 fillstyle transparent solid 0.3",
+                    Mark::StepArea => "with fillsteps fillstyle transparent solid 0.3",
+                };
+                format!(
+                    "{} using {}:{} {} linecolor rgb {} title {}",
+                    gnuplot_string(dat_filename),
+                    2 * i + 1,
+                    2 * i + 2,
+                    style,
+                    gnuplot_string(layer.color()),
+                    gnuplot_string(layer.name())
+                )
+            })
+            .collect();
+        if !plots.is_empty() {
+            let _ = writeln!(gp, "plot {}", plots.join(", \\\n     "));
+        }
+        gp
+    }
+
+    fn to_pgfplots(&self, dat_filename: &str) -> String {
+        let mut tex = String::new();
+        tex.push_str("% Requires \\usepackage{pgfplots} and \\pgfplotsset{compat=1.16}\n");
+        let mut colors = Vec::<String>::new();
+        for (i, (_, color)) in self.chart.legend().iter().enumerate() {
+            let name = format!("{}-{}-{}", self.chart.group(), self.chart.kind().name(), i);
+            let name: String = name
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+                .collect();
+            match color.strip_prefix('#') {
+                Some(hex) if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+                    let _ = writeln!(
+                        tex,
+                        "\\definecolor{{{}}}{{HTML}}{{{}}}",
+                        name,
+                        hex.to_ascii_uppercase()
+                    );
+                    colors.push(name);
+                }
+                _ => colors.push(color.to_string()),
+            }
+        }
+        let legend = self.chart.legend();
+        let color_of = |fn_name: &str| -> &str {
+            legend
+                .iter()
+                .position(|(name, _)| name.as_str() == fn_name)
+                .map(|i| colors[i].as_str())
+                .unwrap_or("black")
+        };
+
+        tex.push_str("\\begin{tikzpicture}\n");
+        let _ = writeln!(
+            tex,
+            "\\begin{{axis}}[title={{{}}}, xlabel={{{}}}, ylabel={{{}}}, width=\\linewidth, height=0.6\\linewidth, grid=major, legend pos=outer north east, legend cell align=left, unbounded coords=discard{}]",
+            latex_escape(&self.chart.title()),
+            latex_escape(&self.chart.x_label()),
+            latex_escape(&self.chart.y_label()),
+            if self.chart.kind() == ChartKind::Pdf {
+                ", ymin=0"
+            } else {
+                ""
+            }
+        );
+        for (i, layer) in self.chart.layers().iter().enumerate() {
+            let color = color_of(layer.fn_name());
+            let (style, cycle) = match layer.mark() {
+                Mark::Points => (
+                    format!("only marks, mark=*, mark size=0.5pt, color={}", color),
+                    "",
+                ),
+                Mark::Line => (format!("no markers, color={}", color), ""),
+                Mark::Area => (
+                    format!(
+                        "no markers, draw={c}, fill={c}, fill opacity=0.3",
+                        c = color
+                    ),
+                    " \\closedcycle",
+                ),
+                Mark::StepArea => (
+                    format!(
+                        "const plot, no markers, draw={c}, fill={c}, fill opacity=0.3",
+                        c = color
+                    ),
+                    " \\closedcycle",
+                ),
+            };
+            let _ = writeln!(
+                tex,
+                "\\addplot[{}] table[x index={}, y index={}] {{{}}}{};",
+                style,
+                2 * i,
+                2 * i + 1,
+                dat_filename,
+                cycle
+            );
+            let _ = writeln!(tex, "\\addlegendentry{{{}}}", latex_escape(layer.name()));
+        }
+        for rule in self.chart.rules() {
+            let _ = writeln!(
+                tex,
+                "\\draw[dashed, color={}] ({{axis cs:{x},0}}|-{{rel axis cs:0,0}}) -- ({{axis cs:{x},0}}|-{{rel axis cs:0,1}});",
+                color_of(rule.fn_name()),
+                x = rule.x()
+            );
+        }
+        tex.push_str("\\end{axis}\n");
+        tex.push_str("\\end{tikzpicture}\n");
+        tex
+    }
+}
+
+fn dat_number(val: f64) -> String {
+    if val.is_finite() {
+        val.to_string()
+    } else {
+        "nan".to_string()
+    }
+}
+
+/// A single quoted gnuplot string in which '' is a quote
+fn gnuplot_string(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+fn latex_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::chart::ChartStyle;
+    use crate::rawdata::CriterionData;
+    use crate::timeunit::TimeUnit;
+    use crate::tsxdata::TsxData;
+
+    #[test]
+    fn test_plot_script() {
+        let mut cdata = CriterionData::new();
+        for (i, measurement) in [10.0, 20.5, 29.5, 40.0].iter().enumerate() {
+            cdata.insert(
+                "Push".to_string(),
+                "vec_push".to_string(),
+                *measurement,
+                (i + 1) as u32,
+                TimeUnit::NS,
+                None,
+            );
+        }
+        let tt_data = cdata.total_time_data(None).unwrap();
+        let chart =
+            Chart::linear_regression("push", tt_data.get("push").unwrap(), &ChartStyle::new());
+
+        let gnuplot = PlotScript::new(&chart, ScriptFormat::Gnuplot);
+        let dat = gnuplot.to_dat();
+        let mut lines = dat.lines();
+        assert_eq!(Some("# push: Linear Regression"), lines.next());
+        assert_eq!(
+            Some("# \"vec_push\" (x y) \"LR (vec_push)\" (x y)"),
+            lines.next()
+        );
+        assert!(lines.next().unwrap().starts_with("1 10 1 "));
+        assert!(lines.next().unwrap().starts_with("2 20.5 4 "));
+        assert_eq!(Some("3 29.5 nan nan"), lines.next());
+
+        let gp = gnuplot.to_script("push-lr.dat");
+        assert!(gp.contains("set output 'push-lr.pdf'\n"));
+        assert!(gp.contains("set ylabel 'Total Sample Time (ns)'\n"));
+        assert!(gp.contains(
+            "plot 'push-lr.dat' using 1:2 with points pointtype 7 pointsize 0.3 linecolor rgb '#1f77b4' title 'vec_push', \\\n     'push-lr.dat' using 3:4 with lines"
+        ));
+
+        let mt_data = cdata.mean_time_data(None).unwrap();
+        let dataset = mt_data.get("push").unwrap();
+        let pdf_data = mt_data.data().pdf_data("push").unwrap();
+        let chart = Chart::pdf("push", dataset, &pdf_data, &ChartStyle::new());
+        let tex = PlotScript::new(&chart, ScriptFormat::Pgfplots).to_script("push-pdf.dat");
+        assert!(tex.contains("\\definecolor{push-pdf-0}{HTML}{1F77B4}\n"));
+        assert!(tex.contains("xlabel={Mean Time Per Iteration (ns)}, ylabel={Density}"));
+        assert!(tex.contains(
+            "\\addplot[no markers, draw=push-pdf-0, fill=push-pdf-0, fill opacity=0.3] table[x index=0, y index=1] {push-pdf.dat} \\closedcycle;\n\\addlegendentry{Density (vec\\_push)}\n"
+        ));
+        assert!(tex.contains("\\draw[dashed, color=push-pdf-0] ({axis cs:10.02"));
+        assert!(tex.ends_with("\\end{axis}\n\\end{tikzpicture}\n"));
+    }
+}
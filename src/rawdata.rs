@@ -1,15 +1,27 @@
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
+use crate::infomap::ComparisonMap;
+use crate::infomap::ComplexityMap;
 use crate::infomap::SeriesInfoMap;
+use crate::infomap::StatsInfoMap;
+use crate::stats::BvAnalysis;
+use crate::stats::Comparison;
+use crate::stats::ComplexityFit;
+use crate::stats::SuiteScore;
+use crate::stats::UvAnalysis;
 use crate::timeunit::TimeUnit;
+use crate::tsxdata::ComplexityData;
 use crate::tsxdata::MeanTimeData;
+use crate::tsxdata::SuiteMatrix;
 use crate::tsxdata::TotalTimeData;
 use crate::tsxdata::TsxData;
 use crate::FnName;
 use crate::GroupName;
 use crate::IterCount;
 use crate::Measure;
+use crate::Param;
+use crate::SampleIndex;
 use crate::YIndex;
 
 use std::error::Error;
@@ -20,6 +32,8 @@ pub struct CriterionDataPoint {
     iter_count: IterCount,
     measurement: Measure,
     time_unit: TimeUnit,
+    param: Option<Param>,
+    sample_index: SampleIndex,
 }
 
 /// A set of raw data points.
@@ -41,11 +55,15 @@ impl CriterionDataPoint {
         measurement: Measure,
         iter_count: IterCount,
         time_unit: TimeUnit,
+        param: Option<Param>,
+        sample_index: SampleIndex,
     ) -> CriterionDataPoint {
         CriterionDataPoint {
             iter_count,
             measurement,
             time_unit,
+            param,
+            sample_index,
         }
     }
 
@@ -61,15 +79,34 @@ impl CriterionDataPoint {
         self.time_unit
     }
 
+    /// The numeric parameter of the benchmark (the `value` column of the
+    /// raw.csv file) if one was specified.
+    pub fn param(&self) -> Option<Param> {
+        self.param
+    }
+
+    /// The position of the sample in the order in which Criterion took it
+    pub fn sample_index(&self) -> SampleIndex {
+        self.sample_index
+    }
+
     pub fn as_mean_time(&self) -> CriterionDataPoint {
         CriterionDataPoint {
             measurement: self.measurement / (self.iter_count as f64),
             iter_count: self.iter_count,
             time_unit: self.time_unit,
+            param: self.param,
+            sample_index: self.sample_index,
         }
     }
 }
 
+impl Default for CriterionDataSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl CriterionDataSet {
     pub fn new() -> CriterionDataSet {
         CriterionDataSet {
@@ -77,8 +114,16 @@ impl CriterionDataSet {
         }
     }
 
-    pub fn insert(&mut self, measurement: Measure, iter_count: IterCount, time_unit: TimeUnit) {
-        let datapoint = CriterionDataPoint::new(measurement, iter_count, time_unit);
+    pub fn insert(
+        &mut self,
+        measurement: Measure,
+        iter_count: IterCount,
+        time_unit: TimeUnit,
+        param: Option<Param>,
+    ) {
+        let sample_index = self.dataset.len() as SampleIndex;
+        let datapoint =
+            CriterionDataPoint::new(measurement, iter_count, time_unit, param, sample_index);
         self.dataset.push(datapoint);
     }
 
@@ -89,6 +134,77 @@ impl CriterionDataSet {
     pub fn len(&self) -> usize {
         self.dataset.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.dataset.is_empty()
+    }
+
+    pub fn time_unit(&self) -> Option<TimeUnit> {
+        self.dataset.first().map(|datapoint| datapoint.time_unit())
+    }
+
+    /// Univariate analysis of the mean time per iteration of each sample
+    pub fn uv_analysis(&self, fn_name: &str) -> UvAnalysis {
+        let mut uva = UvAnalysis::new(fn_name.to_string());
+        for datapoint in &self.dataset {
+            uva.add(datapoint.as_mean_time().measurement());
+        }
+        uva
+    }
+
+    /// Univariate analysis of the mean time per iteration of each sample in
+    /// nanoseconds
+    pub fn uv_analysis_nanos(&self, fn_name: &str) -> UvAnalysis {
+        let mut uva = UvAnalysis::new(fn_name.to_string());
+        for datapoint in &self.dataset {
+            let mean_time = datapoint.as_mean_time();
+            uva.add(mean_time.time_unit().to_nanos(mean_time.measurement()));
+        }
+        uva
+    }
+
+    /// Linear regression of the total time in nanoseconds of each sample
+    /// against its iteration count
+    pub fn bv_analysis_nanos(&self) -> BvAnalysis {
+        let mut bva = BvAnalysis::new();
+        for datapoint in &self.dataset {
+            bva.add(
+                datapoint.iter_count() as f64,
+                datapoint.time_unit().to_nanos(datapoint.measurement()),
+            );
+        }
+        bva
+    }
+
+    /// The mean time per iteration for each distinct parameter value, sorted
+    /// by parameter. Samples without a parameter are ignored.
+    pub fn param_means(&self) -> Vec<(Param, Measure)> {
+        let mut sums = Vec::<(Param, Measure, u32)>::new();
+        for datapoint in &self.dataset {
+            let param = match datapoint.param() {
+                None => continue,
+                Some(param) => param,
+            };
+            let mean_time = datapoint.as_mean_time().measurement();
+            match sums.iter_mut().find(|(p, _, _)| *p == param) {
+                Some(sum) => {
+                    sum.1 += mean_time;
+                    sum.2 += 1;
+                }
+                None => sums.push((param, mean_time, 1)),
+            }
+        }
+        sums.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        sums.iter()
+            .map(|(param, sum, count)| (*param, sum / *count as f64))
+            .collect()
+    }
+}
+
+impl Default for CriterionFnData {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl CriterionFnData {
@@ -104,23 +220,35 @@ impl CriterionFnData {
         measurement: Measure,
         iter_count: IterCount,
         time_unit: TimeUnit,
+        param: Option<Param>,
     ) {
         let function_key = function.to_ascii_lowercase();
         match self.fn_map.get_mut(&function_key) {
             None => {
                 let mut c_dataset = CriterionDataSet::new();
-                c_dataset.insert(measurement, iter_count, time_unit);
+                c_dataset.insert(measurement, iter_count, time_unit, param);
                 self.fn_map.insert(function_key, c_dataset);
                 self.fn_names.insert(function);
             }
             Some(c_dataset) => {
-                c_dataset.insert(measurement, iter_count, time_unit);
+                c_dataset.insert(measurement, iter_count, time_unit, param);
             }
         }
     }
     pub fn get(&self, function: &str) -> Option<&CriterionDataSet> {
         self.fn_map.get(&function.to_ascii_lowercase())
     }
+
+    /// The lowercase names of the functions
+    pub fn functions(&self) -> Vec<&FnName> {
+        self.fn_map.keys().collect()
+    }
+}
+
+impl Default for CriterionData {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl CriterionData {
@@ -130,6 +258,10 @@ impl CriterionData {
         }
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
     pub fn groups(&self) -> Vec<GroupName> {
         let mut groups = Vec::<GroupName>::new();
         for group_name in self.data.keys() {
@@ -145,16 +277,17 @@ impl CriterionData {
         measurement: Measure,
         iter_count: IterCount,
         time_unit: TimeUnit,
+        param: Option<Param>,
     ) {
         let group_key = group.to_ascii_lowercase();
         match self.data.get_mut(&group_key) {
             None => {
                 let mut fn_data = CriterionFnData::new();
-                fn_data.insert(function, measurement, iter_count, time_unit);
+                fn_data.insert(function, measurement, iter_count, time_unit, param);
                 self.data.insert(group_key, fn_data);
             }
             Some(fn_data) => {
-                fn_data.insert(function, measurement, iter_count, time_unit);
+                fn_data.insert(function, measurement, iter_count, time_unit, param);
             }
         };
     }
@@ -170,16 +303,14 @@ impl CriterionData {
         data: &mut T,
         mean_time: bool,
     ) {
-        let mut y_index: YIndex = 0;
-        for (fn_name, cdataset) in &fn_map.fn_map {
+        for (y_index, (fn_name, cdataset)) in (0 as YIndex..).zip(&fn_map.fn_map) {
             for datapoint in &cdataset.dataset {
                 if mean_time {
-                    data.push(group, &fn_name, &datapoint.as_mean_time(), y_index);
+                    data.push(group, fn_name, &datapoint.as_mean_time(), y_index);
                 } else {
-                    data.push(group, &fn_name, &datapoint, y_index);
+                    data.push(group, fn_name, datapoint, y_index);
                 }
             }
-            y_index += 1;
         }
     }
 
@@ -190,7 +321,7 @@ impl CriterionData {
             match fn_data {
                 None => return None,
                 Some(fn_data) => {
-                    self.to_tsx_data::<MeanTimeData>(&group, fn_data, &mut mean_time_data, true);
+                    self.to_tsx_data::<MeanTimeData>(group, fn_data, &mut mean_time_data, true);
                     return Some(mean_time_data);
                 }
             }
@@ -209,7 +340,7 @@ impl CriterionData {
             match fn_data {
                 None => return None,
                 Some(fn_data) => {
-                    self.to_tsx_data::<TotalTimeData>(&group, fn_data, &mut total_time_data, false);
+                    self.to_tsx_data::<TotalTimeData>(group, fn_data, &mut total_time_data, false);
                     return Some(total_time_data);
                 }
             }
@@ -226,10 +357,8 @@ impl CriterionData {
         match group_name {
             None => {
                 for (group, fndata) in &self.data {
-                    let mut y_index: YIndex = 0;
-                    for (function, _) in &fndata.fn_map {
+                    for (y_index, function) in (0 as YIndex..).zip(fndata.fn_map.keys()) {
                         si_map.push(group, function, y_index);
-                        y_index += 1;
                     }
                 }
             }
@@ -238,10 +367,8 @@ impl CriterionData {
                 match fn_data {
                     None => return None,
                     Some(fn_data) => {
-                        let mut y_index: YIndex = 0;
-                        for (function, _) in &fn_data.fn_map {
-                            si_map.push(&group_name, function, y_index);
-                            y_index += 1;
+                        for (y_index, function) in (0 as YIndex..).zip(fn_data.fn_map.keys()) {
+                            si_map.push(group_name, function, y_index);
                         }
                     }
                 }
@@ -250,6 +377,211 @@ impl CriterionData {
         Some(si_map)
     }
 
+    /// The dataset of every function in every group
+    pub fn datasets(&self) -> Vec<(&GroupName, &FnName, &CriterionDataSet)> {
+        let mut datasets = Vec::<(&GroupName, &FnName, &CriterionDataSet)>::new();
+        for (group, fn_data) in &self.data {
+            for (fn_name, cdataset) in &fn_data.fn_map {
+                datasets.push((group, fn_name, cdataset));
+            }
+        }
+        datasets
+    }
+
+    /// Univariate analysis of the mean time per iteration in nanoseconds of
+    /// every function in every group
+    pub fn uv_analyses_nanos(&self) -> Vec<(&GroupName, UvAnalysis)> {
+        self.datasets()
+            .into_iter()
+            .map(|(group, fn_name, cdataset)| (group, cdataset.uv_analysis_nanos(fn_name)))
+            .collect()
+    }
+
+    /// The stats of the mean time per iteration in nanoseconds of every
+    /// function in every group
+    pub fn stats_info_map(&self) -> StatsInfoMap {
+        let mut stats_info_map = StatsInfoMap::new();
+        for (group, uva) in self.uv_analyses_nanos() {
+            stats_info_map.push(group, uva.fn_name(), uva.stats_info());
+        }
+        stats_info_map
+    }
+
+    /// The mean time per iteration of every function in every group, and the
+    /// baseline of each group that has one of the `relative_to` functions.
+    pub fn suite_matrix(&self, relative_to: &[FnName]) -> SuiteMatrix {
+        let mut matrix = SuiteMatrix::new();
+        for (group, fn_data) in &self.data {
+            for (fn_name, cdataset) in &fn_data.fn_map {
+                if let Some(time_unit) = cdataset.time_unit() {
+                    let mean = cdataset.uv_analysis(fn_name).mean();
+                    matrix.push(group, fn_name, time_unit.to_nanos(mean));
+                }
+            }
+            let baseline = relative_to
+                .iter()
+                .find(|baseline| fn_data.get(baseline).is_some());
+            if let Some(baseline) = baseline {
+                matrix.set_baseline(group, &baseline.to_ascii_lowercase());
+            }
+        }
+        matrix
+    }
+
+    /// The geometric mean of the ratio of the mean time per iteration of
+    /// `fn_a` over `fn_b` across every group that has both functions.
+    /// Returns None if no group has both.
+    pub fn suite_score(&self, fn_a: &str, fn_b: &str) -> Option<SuiteScore> {
+        let pairs: Vec<(UvAnalysis, UvAnalysis)> = self
+            .data
+            .values()
+            .filter_map(|fn_data| {
+                let uva_a = fn_data.get(fn_a)?.uv_analysis(fn_a);
+                let uva_b = fn_data.get(fn_b)?.uv_analysis(fn_b);
+                Some((uva_a, uva_b))
+            })
+            .collect();
+        SuiteScore::new(fn_a, fn_b, &pairs)
+    }
+
+    /// The samples of every function in every group of this run paired with
+    /// the samples of the same function in the `baseline` run
+    fn baseline_pairs(
+        &self,
+        baseline: &CriterionData,
+    ) -> Vec<(&GroupName, UvAnalysis, UvAnalysis)> {
+        let mut pairs = Vec::<(&GroupName, UvAnalysis, UvAnalysis)>::new();
+        for (group, fn_data) in &self.data {
+            let base_fn_data = match baseline.get(group) {
+                Some(base_fn_data) => base_fn_data,
+                None => continue,
+            };
+            for (fn_name, cdataset) in &fn_data.fn_map {
+                if let Some(base_cdataset) = base_fn_data.get(fn_name) {
+                    pairs.push((
+                        group,
+                        cdataset.uv_analysis(fn_name),
+                        base_cdataset.uv_analysis(fn_name),
+                    ));
+                }
+            }
+        }
+        pairs
+    }
+
+    /// The geometric mean of the ratio of the mean time per iteration of
+    /// every function over the same function in the `baseline` run. Returns
+    /// None if the runs have no group and function in common.
+    pub fn baseline_score(&self, baseline: &CriterionData) -> Option<SuiteScore> {
+        let pairs: Vec<(UvAnalysis, UvAnalysis)> = self
+            .baseline_pairs(baseline)
+            .into_iter()
+            .map(|(_, uva, base_uva)| (uva, base_uva))
+            .collect();
+        SuiteScore::new("current", "baseline", &pairs)
+    }
+
+    /// Compares every function in every group of this run against the same
+    /// function in the `baseline` run. Functions with fewer than two samples
+    /// in either run are skipped.
+    pub fn compare_to_baseline(&self, baseline: &CriterionData) -> Vec<(GroupName, Comparison)> {
+        self.baseline_pairs(baseline)
+            .into_iter()
+            .filter_map(|(group, uva, base_uva)| {
+                Some((group.to_string(), Comparison::new(&uva, &base_uva)?))
+            })
+            .collect()
+    }
+
+    /// Compares the mean time per iteration of two functions in a group.
+    /// Returns None if the group or either function does not exist or has
+    /// fewer than two samples.
+    pub fn compare(&self, group: &str, fn_a: &str, fn_b: &str) -> Option<Comparison> {
+        let fn_data = self.get(group)?;
+        let uva_a = fn_data.get(fn_a)?.uv_analysis(fn_a);
+        let uva_b = fn_data.get(fn_b)?.uv_analysis(fn_b);
+        Comparison::new(&uva_a, &uva_b)
+    }
+
+    fn push_comparisons(group: &str, fn_data: &CriterionFnData, cmp_map: &mut ComparisonMap) {
+        let analyses: Vec<UvAnalysis> = fn_data
+            .fn_map
+            .iter()
+            .map(|(fn_name, cdataset)| cdataset.uv_analysis(fn_name))
+            .collect();
+        for (i, uva_a) in analyses.iter().enumerate() {
+            for uva_b in &analyses[i + 1..] {
+                if let Some(cmp) = Comparison::new(uva_a, uva_b) {
+                    cmp_map.push(group, cmp);
+                }
+            }
+        }
+    }
+
+    pub fn comparison_map(&self, group_name: Option<&str>) -> Option<ComparisonMap> {
+        let mut cmp_map = ComparisonMap::new();
+        match group_name {
+            None => {
+                for (group, fn_data) in &self.data {
+                    CriterionData::push_comparisons(group, fn_data, &mut cmp_map);
+                }
+            }
+            Some(group_name) => {
+                let fn_data = self.data.get(group_name)?;
+                CriterionData::push_comparisons(group_name, fn_data, &mut cmp_map);
+            }
+        }
+        Some(cmp_map)
+    }
+
+    /// Fits the complexity models to the mean time per iteration of a
+    /// function against its parameter and returns the best fit. Returns None
+    /// if the function was benchmarked with fewer than two parameters.
+    pub fn complexity_fit(&self, group: &str, function: &str) -> Option<ComplexityFit> {
+        let cdataset = self.get(group)?.get(function)?;
+        ComplexityFit::best(&cdataset.param_means())
+    }
+
+    fn group_fn_data<'a>(
+        &'a self,
+        group_name: Option<&'a str>,
+    ) -> Option<Vec<(&'a str, &'a CriterionFnData)>> {
+        match group_name {
+            None => Some(
+                self.data
+                    .iter()
+                    .map(|(group, fn_data)| (group.as_str(), fn_data))
+                    .collect(),
+            ),
+            Some(group_name) => Some(vec![(group_name, self.data.get(group_name)?)]),
+        }
+    }
+
+    pub fn complexity_map(&self, group_name: Option<&str>) -> Option<ComplexityMap> {
+        let mut cx_map = ComplexityMap::new();
+        for (group, fn_data) in self.group_fn_data(group_name)? {
+            for (fn_name, cdataset) in &fn_data.fn_map {
+                if let Some(fit) = ComplexityFit::best(&cdataset.param_means()) {
+                    cx_map.push(group, fn_name, fit);
+                }
+            }
+        }
+        Some(cx_map)
+    }
+
+    pub fn complexity_data(&self, group_name: Option<&str>) -> Option<ComplexityData> {
+        let mut cx_data = ComplexityData::new();
+        for (group, fn_data) in self.group_fn_data(group_name)? {
+            for (y_index, cdataset) in (0 as YIndex..).zip(fn_data.fn_map.values()) {
+                let param_means = cdataset.param_means();
+                if let Some(fit) = ComplexityFit::best(&param_means) {
+                    cx_data.push(group, &param_means, &fit, y_index);
+                }
+            }
+        }
+        Some(cx_data)
+    }
+
     pub fn load(&mut self, file_path: &str) -> Result<(), Box<dyn Error>> {
         let file = File::open(file_path)?;
         let mut rdr = csv::ReaderBuilder::new().from_reader(file);
@@ -262,6 +594,7 @@ impl CriterionData {
                 record[5].parse::<Measure>()?,
                 record[7].parse::<IterCount>()?,
                 record[6].parse::<TimeUnit>()?,
+                record[2].parse::<Param>().ok(),
             );
         }
         Ok(())
@@ -269,8 +602,11 @@ impl CriterionData {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 pub mod test {
     use super::*;
+    use crate::infomap::RelativeMap;
+    use crate::meanband::MeanBand;
 
     #[test]
     fn test_cdata() {
@@ -283,6 +619,7 @@ pub mod test {
             measurement,
             iter_count,
             super::TimeUnit::NS,
+            None,
         );
 
         let fn_data: &CriterionFnData;
@@ -308,4 +645,198 @@ pub mod test {
             assert!(false);
         }
     }
+
+    #[test]
+    fn test_drift_data() {
+        let mut cdata = CriterionData::new();
+        // Later samples have fewer iterations so that the iteration count
+        // order is the reverse of the sample order
+        for i in 0..12 {
+            let iter_count: IterCount = (12 - i) * 10;
+            cdata.insert(
+                "Push".to_string(),
+                "Vec".to_string(),
+                (i + 1) as f64 * iter_count as f64,
+                iter_count,
+                TimeUnit::NS,
+                None,
+            );
+        }
+        let mean_time_data = cdata.mean_time_data(Some("push")).unwrap();
+        let drift_data = mean_time_data.data().get("push").unwrap().to_drift_data();
+
+        assert_eq!(&vec![(0, 1.0, 1.0)], drift_data.get(0).unwrap());
+        assert_eq!(&vec![(0, 2.0, 1.5)], drift_data.get(1).unwrap());
+        // The moving average only covers the last 10 samples
+        assert_eq!(&vec![(0, 12.0, 7.5)], drift_data.get(11).unwrap());
+        assert!(drift_data.get(12).is_none());
+    }
+
+    #[test]
+    fn test_residual_data() {
+        let mut cdata = CriterionData::new();
+        let iter_counts: Vec<IterCount> = (1..=10).map(|i| i * 100).collect();
+        for (i, iter_count) in iter_counts.iter().enumerate() {
+            let noise = if i % 2 == 0 { 5.0 } else { -3.0 };
+            cdata.insert(
+                "Push".to_string(),
+                "Vec".to_string(),
+                2.0 * *iter_count as f64 + 100.0 + noise,
+                *iter_count,
+                TimeUnit::NS,
+                None,
+            );
+        }
+        let total_time_data = cdata.total_time_data(Some("push")).unwrap();
+        let residual_data = total_time_data
+            .data()
+            .get("push")
+            .unwrap()
+            .to_residual_data();
+
+        let residuals: Vec<Measure> = iter_counts
+            .iter()
+            .flat_map(|iter_count| residual_data.get(*iter_count).unwrap())
+            .map(|(y_index, residual)| {
+                assert_eq!(0, *y_index);
+                *residual
+            })
+            .collect();
+        assert_eq!(iter_counts.len(), residuals.len());
+        assert!(residuals.iter().sum::<f64>().abs() < 1e-6);
+        assert!(residuals.iter().all(|residual| residual.abs() < 10.0));
+    }
+
+    #[test]
+    fn test_mean_bands() {
+        let mut cdata = CriterionData::new();
+        for measurement in [10.0, 12.0, 11.0, 13.0, 9.0] {
+            cdata.insert(
+                "Push".to_string(),
+                "Vec".to_string(),
+                measurement,
+                1,
+                TimeUnit::NS,
+                None,
+            );
+        }
+        cdata.insert(
+            "Push".to_string(),
+            "VecDeque".to_string(),
+            10.0,
+            1,
+            TimeUnit::NS,
+            None,
+        );
+
+        let mut mean_time_data = cdata.mean_time_data(Some("push")).unwrap();
+        assert!(mean_time_data
+            .data()
+            .get("push")
+            .unwrap()
+            .mean_bands()
+            .is_empty());
+
+        mean_time_data.set_mean_band(MeanBand::StdDev(2.0));
+        let dataset = mean_time_data.data().get("push").unwrap();
+        let std_dev = dataset.uv_analysis(0).unwrap().std_dev();
+        let mean_ci = dataset.uv_analysis(0).unwrap().mean_ci();
+        let bands = dataset.mean_bands();
+        // VecDeque has a single sample and no band
+        assert_eq!(1, bands.len());
+        let (mean, lower, upper) = bands[&0];
+        assert_eq!(11.0, mean);
+        assert!((lower - (11.0 - 2.0 * std_dev)).abs() < 1e-12);
+        assert!((upper - (11.0 + 2.0 * std_dev)).abs() < 1e-12);
+
+        mean_time_data.set_mean_band(MeanBand::ConfidenceInterval);
+        let (_, lower, upper) = mean_time_data.data().get("push").unwrap().mean_bands()[&0];
+        assert_eq!(mean_ci, (lower, upper));
+    }
+
+    #[test]
+    fn test_relatives() {
+        let mut cdata = CriterionData::new();
+        let samples = [
+            ("Vec", [10.0, 12.0, 11.0, 13.0, 9.0]),
+            ("LinkedList", [22.0, 24.0, 20.0, 23.0, 21.0]),
+        ];
+        for (fn_name, sample) in samples {
+            for (i, mean_time) in sample.iter().enumerate() {
+                let iter_count = i as IterCount + 1;
+                cdata.insert(
+                    "Push".to_string(),
+                    fn_name.to_string(),
+                    mean_time * iter_count as f64,
+                    iter_count,
+                    TimeUnit::NS,
+                    None,
+                );
+            }
+        }
+        let mean_time_data = cdata.mean_time_data(Some("push")).unwrap();
+        let dataset = mean_time_data.data().get("push").unwrap();
+        let relative_to = vec!["vec".to_string()];
+
+        let mut relative_map = RelativeMap::new();
+        dataset.get_relatives("push", &relative_to, &mut relative_map);
+        let relatives = relative_map.get("push").unwrap();
+        assert_eq!(2, relatives.len());
+        assert_eq!("vec", relatives["vec"].baseline());
+        assert_eq!(1.0, relatives["vec"].comparison().ratio());
+        assert_eq!(2.0, relatives["linkedlist"].comparison().ratio());
+
+        let mut no_baseline = RelativeMap::new();
+        dataset.get_relatives("push", &["Deque".to_string()], &mut no_baseline);
+        assert!(no_baseline.get("push").is_none());
+
+        // LinkedList is y0 and Vec is y1
+        let relative_data = dataset.to_relative_data(&relative_to);
+        assert_eq!(
+            &vec![(0, 2.0), (1, 10.0 / 11.0)],
+            relative_data.get(1).unwrap()
+        );
+        let baseline_sum: f64 = (1..=5)
+            .flat_map(|iter_count| relative_data.get(iter_count).unwrap())
+            .filter(|(y_index, _)| *y_index == 1)
+            .map(|(_, ratio)| ratio)
+            .sum();
+        assert!((baseline_sum / 5.0 - 1.0).abs() < 1e-12);
+        assert!(dataset.to_relative_data(&[]).get(1).is_none());
+    }
+
+    #[test]
+    fn test_suite_matrix() {
+        let mut cdata = CriterionData::new();
+        let samples = [
+            ("Push", "Vec", [10.0, 12.0], TimeUnit::NS),
+            ("Push", "LinkedList", [20.0, 24.0], TimeUnit::NS),
+            ("Pop", "Vec", [1.5, 2.5], TimeUnit::US),
+        ];
+        for (group, fn_name, sample, time_unit) in samples {
+            for measurement in sample {
+                cdata.insert(
+                    group.to_string(),
+                    fn_name.to_string(),
+                    measurement,
+                    1,
+                    time_unit,
+                    None,
+                );
+            }
+        }
+
+        let matrix = cdata.suite_matrix(&["Deque".to_string(), "LinkedList".to_string()]);
+        assert_eq!(
+            vec!["linkedlist", "vec"],
+            matrix.functions().into_iter().collect::<Vec<&FnName>>()
+        );
+        assert_eq!(Some(11.0), matrix.mean("Push", "Vec"));
+        assert_eq!(Some(22.0), matrix.mean("push", "linkedlist"));
+        // Means are converted to nanoseconds
+        assert_eq!(Some(2000.0), matrix.mean("pop", "vec"));
+        assert_eq!(None, matrix.mean("pop", "linkedlist"));
+        assert_eq!(Some(&"linkedlist".to_string()), matrix.baseline("push"));
+        assert_eq!(None, matrix.baseline("pop"));
+    }
 }
@@ -0,0 +1,269 @@
+use crate::rawdata::CriterionData;
+use crate::stats::Comparison;
+use crate::GroupName;
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Display;
+
+const DEFAULT_THRESHOLD: f64 = 0.05;
+const DEFAULT_SIGNIFICANCE: f64 = 0.05;
+
+/// How the mean time per iteration of a function changed against the
+/// baseline run
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Verdict {
+    /// Slower by more than the threshold with statistical significance
+    Regression,
+    /// Faster by more than the threshold with statistical significance
+    Improvement,
+    Unchanged,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Regression => write!(f, "REGRESSION"),
+            Verdict::Improvement => write!(f, "IMPROVEMENT"),
+            Verdict::Unchanged => write!(f, "UNCHANGED"),
+        }
+    }
+}
+
+/// The thresholds that a change in the mean time per iteration has to
+/// exceed before it is reported
+pub struct RegressionCheck {
+    threshold: f64,
+    group_thresholds: BTreeMap<GroupName, f64>,
+    significance: f64,
+}
+
+/// The result of comparing one function against the baseline run
+pub struct RegressionResult {
+    group: GroupName,
+    comparison: Comparison,
+    threshold: f64,
+    verdict: Verdict,
+}
+
+/// The result of comparing every function of a run against the baseline
+/// run
+pub struct RegressionReport {
+    results: Vec<RegressionResult>,
+}
+
+impl Default for RegressionCheck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RegressionCheck {
+    pub fn new() -> RegressionCheck {
+        RegressionCheck {
+            threshold: DEFAULT_THRESHOLD,
+            group_thresholds: BTreeMap::<GroupName, f64>::new(),
+            significance: DEFAULT_SIGNIFICANCE,
+        }
+    }
+
+    /// Sets the relative change in the mean e.g. 0.05 for 5% that every
+    /// group without its own threshold has to exceed
+    pub fn set_threshold(&mut self, threshold: f64) {
+        self.threshold = threshold;
+    }
+
+    pub fn set_group_threshold(&mut self, group: &str, threshold: f64) {
+        self.group_thresholds
+            .insert(group.to_ascii_lowercase(), threshold);
+    }
+
+    /// Sets the p-value of Welch's t-test below which a change is
+    /// significant
+    pub fn set_significance(&mut self, significance: f64) {
+        self.significance = significance;
+    }
+
+    pub fn threshold(&self, group: &str) -> f64 {
+        match self.group_thresholds.get(group) {
+            Some(threshold) => *threshold,
+            None => self.threshold,
+        }
+    }
+
+    /// Compares every function of the `current` run that is also in the
+    /// `baseline` run
+    pub fn check(&self, current: &CriterionData, baseline: &CriterionData) -> RegressionReport {
+        let results = current
+            .compare_to_baseline(baseline)
+            .into_iter()
+            .map(|(group, comparison)| {
+                let threshold = self.threshold(&group);
+                let change = comparison.ratio() - 1.0;
+                let verdict = if comparison.t_p_value() >= self.significance {
+                    Verdict::Unchanged
+                } else if change > threshold {
+                    Verdict::Regression
+                } else if change < -threshold {
+                    Verdict::Improvement
+                } else {
+                    Verdict::Unchanged
+                };
+                RegressionResult {
+                    group,
+                    comparison,
+                    threshold,
+                    verdict,
+                }
+            })
+            .collect();
+        RegressionReport { results }
+    }
+}
+
+impl RegressionResult {
+    pub fn group(&self) -> &GroupName {
+        &self.group
+    }
+
+    pub fn function(&self) -> &String {
+        self.comparison.fn_a()
+    }
+
+    /// The comparison of the current run (a) against the baseline run (b)
+    pub fn comparison(&self) -> &Comparison {
+        &self.comparison
+    }
+
+    /// The relative change in the mean time per iteration. Positive values
+    /// are slower than the baseline.
+    pub fn change(&self) -> f64 {
+        self.comparison.ratio() - 1.0
+    }
+
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    pub fn verdict(&self) -> Verdict {
+        self.verdict
+    }
+}
+
+impl Display for RegressionResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}/{}: {:+.1}% (95% CI {:+.1}% to {:+.1}%, p {:.1e}, threshold {:.1}%)",
+            self.verdict,
+            self.group,
+            self.function(),
+            self.change() * 100.0,
+            (self.comparison.ci_lower() - 1.0) * 100.0,
+            (self.comparison.ci_upper() - 1.0) * 100.0,
+            self.comparison.t_p_value(),
+            self.threshold * 100.0
+        )
+    }
+}
+
+impl RegressionReport {
+    pub fn results(&self) -> &Vec<RegressionResult> {
+        &self.results
+    }
+
+    pub fn get(&self, group: &str, function: &str) -> Option<&RegressionResult> {
+        self.results.iter().find(|result| {
+            result.group.eq_ignore_ascii_case(group)
+                && result.function().eq_ignore_ascii_case(function)
+        })
+    }
+
+    pub fn regressions(&self) -> Vec<&RegressionResult> {
+        self.with_verdict(Verdict::Regression)
+    }
+
+    pub fn improvements(&self) -> Vec<&RegressionResult> {
+        self.with_verdict(Verdict::Improvement)
+    }
+
+    pub fn has_regressions(&self) -> bool {
+        self.results
+            .iter()
+            .any(|result| result.verdict == Verdict::Regression)
+    }
+
+    fn with_verdict(&self, verdict: Verdict) -> Vec<&RegressionResult> {
+        self.results
+            .iter()
+            .filter(|result| result.verdict == verdict)
+            .collect()
+    }
+}
+
+impl Display for RegressionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for result in self.regressions().iter().chain(self.improvements().iter()) {
+            writeln!(f, "{}", result)?;
+        }
+        write!(
+            f,
+            "{} regressions, {} improvements, {} unchanged",
+            self.regressions().len(),
+            self.improvements().len(),
+            self.with_verdict(Verdict::Unchanged).len()
+        )
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::timeunit::TimeUnit;
+
+    fn insert(cdata: &mut CriterionData, group: &str, function: &str, sample: &[f64]) {
+        for measurement in sample {
+            cdata.insert(
+                group.to_string(),
+                function.to_string(),
+                *measurement,
+                1,
+                TimeUnit::NS,
+                None,
+            );
+        }
+    }
+
+    #[test]
+    fn test_regression_check() {
+        let base_sample = [10.0, 10.2, 9.8, 10.1, 9.9, 10.0, 10.3, 9.7];
+        let slow_sample: Vec<f64> = base_sample.iter().map(|m| m * 1.2).collect();
+        let fast_sample: Vec<f64> = base_sample.iter().map(|m| m * 0.8).collect();
+
+        let mut baseline = CriterionData::new();
+        insert(&mut baseline, "Push", "a", &base_sample);
+        insert(&mut baseline, "Push", "b", &base_sample);
+        insert(&mut baseline, "Pop", "a", &base_sample);
+
+        let mut current = CriterionData::new();
+        insert(&mut current, "Push", "a", &slow_sample);
+        insert(&mut current, "Push", "b", &fast_sample);
+        insert(&mut current, "Pop", "a", &slow_sample);
+
+        let mut check = RegressionCheck::new();
+        check.set_group_threshold("Pop", 0.25);
+        let report = check.check(&current, &baseline);
+        assert_eq!(3, report.results().len());
+        assert!(report.has_regressions());
+
+        let regressions = report.regressions();
+        assert_eq!(1, regressions.len());
+        assert_eq!("push", regressions[0].group());
+        assert_eq!("a", regressions[0].function());
+        assert!((regressions[0].change() - 0.2).abs() < 1e-9);
+        assert_eq!(1, report.improvements().len());
+
+        check.set_threshold(0.25);
+        assert!(!check.check(&current, &baseline).has_regressions());
+    }
+}
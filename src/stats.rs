@@ -1,4 +1,5 @@
 use crate::{infomap::StatsInfo, Density, KdeXVal, SAMPLE_SIZE};
+use std::fmt;
 
 pub struct UvAnalysis {
     fn_name: String,
@@ -14,7 +15,7 @@ pub struct UvAnalysis {
 impl UvAnalysis {
     pub fn new(fn_name: String) -> UvAnalysis {
         UvAnalysis {
-            fn_name: fn_name,
+            fn_name,
             sample: Vec::<f64>::with_capacity(SAMPLE_SIZE),
             sigma_x: 0.0,
             sigma_xsq: 0.0,
@@ -27,8 +28,8 @@ impl UvAnalysis {
 
     pub fn add(&mut self, val: f64) {
         self.sample.push(val);
-        self.sigma_x += val as f64;
-        self.sigma_xsq += val.powi(2) as f64;
+        self.sigma_x += val;
+        self.sigma_xsq += val.powi(2);
         self.n += 1;
         self.x_bar = self.sigma_x / self.n as f64;
 
@@ -55,6 +56,14 @@ impl UvAnalysis {
         &self.fn_name
     }
 
+    pub fn sample(&self) -> &Vec<f64> {
+        &self.sample
+    }
+
+    pub fn n(&self) -> u32 {
+        self.n
+    }
+
     pub fn variance(&self) -> f64 {
         (self.sigma_xsq - (self.sigma_x.powi(2) / self.n as f64)) / (self.n - 1) as f64
     }
@@ -68,11 +77,149 @@ impl UvAnalysis {
     }
 
     fn kde_bw(&self) -> f64 {
-        self.std_dev() * ((4.0 / (3.0 * self.n as f64)) as f64).powf((1.0 / 5.0) as f64)
+        self.std_dev() * (4.0 / (3.0 * self.n as f64)).powf(1.0 / 5.0)
     }
 
     pub fn stats_info(&self) -> StatsInfo {
-        StatsInfo::new(self.x_bar, self.std_dev())
+        StatsInfo::new(
+            self.x_bar,
+            percentile(&sorted(&self.sample), 0.5),
+            self.std_dev(),
+            self.mean_ci(),
+            self.diagnostics(),
+        )
+    }
+
+    /// The confidence interval of the mean
+    pub fn mean_ci(&self) -> (f64, f64) {
+        let n = self.n as f64;
+        let t = student_t_quantile(0.5 + CONFIDENCE_LEVEL / 2.0, n - 1.0);
+        let half_width = t * self.std_dev() / n.sqrt();
+        (self.mean() - half_width, self.mean() + half_width)
+    }
+
+    /// Percentile bootstrap confidence interval of the median
+    pub fn median_ci(&self) -> (f64, f64) {
+        let mut rng = XorShift::new(BOOTSTRAP_SEED);
+        let mut medians = Vec::<f64>::with_capacity(BOOTSTRAP_RESAMPLES);
+        let mut resample = vec![0.0; self.sample.len()];
+        for _ in 0..BOOTSTRAP_RESAMPLES {
+            for val in resample.iter_mut() {
+                *val = self.sample[rng.next_index(self.sample.len())];
+            }
+            medians.push(percentile(&sorted(&resample), 0.5));
+        }
+        let medians = sorted(&medians);
+        let alpha = (1.0 - CONFIDENCE_LEVEL) / 2.0;
+        (
+            percentile(&medians, alpha),
+            percentile(&medians, 1.0 - alpha),
+        )
+    }
+
+    /// The mean and median of the sample along with their confidence
+    /// intervals
+    pub fn summary(&self) -> Summary {
+        let (mean_ci_lower, mean_ci_upper) = self.mean_ci();
+        let (median_ci_lower, median_ci_upper) = self.median_ci();
+        Summary {
+            mean: self.mean(),
+            mean_ci_lower,
+            mean_ci_upper,
+            median: percentile(&sorted(&self.sample), 0.5),
+            median_ci_lower,
+            median_ci_upper,
+        }
+    }
+
+    pub fn coefficient_of_variation(&self) -> f64 {
+        self.std_dev() / self.mean()
+    }
+
+    /// The correlation of each sample with the one taken before it
+    pub fn lag1_autocorrelation(&self) -> f64 {
+        let mean = self.mean();
+        let denom: f64 = self.sample.iter().map(|x| (x - mean).powi(2)).sum();
+        if denom == 0.0 {
+            return 0.0;
+        }
+        let numer: f64 = self
+            .sample
+            .windows(2)
+            .map(|w| (w[0] - mean) * (w[1] - mean))
+            .sum();
+        numer / denom
+    }
+
+    /// The Mann-Kendall test for a monotonic trend in the order the samples
+    /// were taken. Returns Kendall's S statistic and its two sided p-value
+    /// using the normal approximation.
+    pub fn mann_kendall(&self) -> (f64, f64) {
+        let mut s = 0.0;
+        for (i, x) in self.sample.iter().enumerate() {
+            for y in &self.sample[i + 1..] {
+                if y > x {
+                    s += 1.0;
+                } else if y < x {
+                    s -= 1.0;
+                }
+            }
+        }
+        let n = self.n as f64;
+        let sigma = (n * (n - 1.0) * (2.0 * n + 5.0) / 18.0).sqrt();
+        if sigma == 0.0 {
+            return (s, 1.0);
+        }
+        let z = (s.abs() - 1.0).max(0.0) / sigma;
+        (s, (2.0 * (1.0 - normal_cdf(z))).min(1.0))
+    }
+
+    /// Sarle's bimodality coefficient. Values above 5/9 suggest the sample
+    /// is bimodal.
+    pub fn bimodality_coefficient(&self) -> f64 {
+        let n = self.n as f64;
+        let mean = self.mean();
+        let m2 = self.sample.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        if n < 4.0 || m2 == 0.0 {
+            return 0.0;
+        }
+        let m3 = self.sample.iter().map(|x| (x - mean).powi(3)).sum::<f64>() / n;
+        let m4 = self.sample.iter().map(|x| (x - mean).powi(4)).sum::<f64>() / n;
+        let skew = (n * (n - 1.0)).sqrt() / (n - 2.0) * m3 / m2.powf(1.5);
+        let kurt =
+            (n - 1.0) / ((n - 2.0) * (n - 3.0)) * ((n + 1.0) * (m4 / m2.powi(2) - 3.0) + 6.0);
+        (skew.powi(2) + 1.0) / (kurt + 3.0 * (n - 1.0).powi(2) / ((n - 2.0) * (n - 3.0)))
+    }
+
+    /// Warnings about samples that are too noisy, correlated, drifting or
+    /// bimodal for their mean to be trusted.
+    pub fn diagnostics(&self) -> Vec<String> {
+        let mut warnings = Vec::<String>::new();
+        if self.n < 4 {
+            return warnings;
+        }
+        let cv = self.coefficient_of_variation();
+        if cv > CV_THRESHOLD {
+            warnings.push(format!("unstable: CV {:.0}%", cv * 100.0));
+        }
+        let r1 = self.lag1_autocorrelation();
+        if r1.abs() > AUTOCORRELATION_THRESHOLD {
+            warnings.push(format!("autocorrelated: lag-1 r {:.2}", r1));
+        }
+        let (s, p_value) = self.mann_kendall();
+        if p_value < TREND_P_VALUE_THRESHOLD {
+            let direction = if s > 0.0 { "increasing" } else { "decreasing" };
+            warnings.push(format!(
+                "trend: {} (p {:.1e})",
+                direction,
+                p_value.max(f64::EPSILON)
+            ));
+        }
+        let bc = self.bimodality_coefficient();
+        if bc > BIMODALITY_THRESHOLD {
+            warnings.push(format!("bimodal: BC {:.2}", bc));
+        }
+        warnings
     }
 
     pub fn kdensity(&self, x_range: u16) -> KdeDataSet {
@@ -93,6 +240,273 @@ impl UvAnalysis {
         }
         kde_dataset
     }
+
+    /// The KDE of the sample for a violin plot. A sample without variance
+    /// has no KDE so its violin is a single point of zero density at the
+    /// mean.
+    pub fn violin(&self, x_range: u16) -> KdeDataSet {
+        if self.std_dev() > 0.0 {
+            return self.kdensity(x_range);
+        }
+        let mut kde_dataset = KdeDataSet::new();
+        kde_dataset.push(self.mean(), 0.0);
+        kde_dataset
+    }
+
+    /// The empirical cumulative distribution function of the sample as
+    /// (value, cumulative probability) pairs sorted by value.
+    pub fn ecdf(&self) -> Vec<(f64, f64)> {
+        let n = self.n as f64;
+        sorted(&self.sample)
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (*x, (i + 1) as f64 / n))
+            .collect()
+    }
+
+    /// The sorted sample against the quantiles of the standard normal
+    /// distribution at Blom's plotting positions, as (theoretical quantile,
+    /// sample quantile) pairs.
+    pub fn qq(&self) -> Vec<(f64, f64)> {
+        let n = self.n as f64;
+        sorted(&self.sample)
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (normal_quantile((i as f64 + 0.625) / (n + 0.25)), *x))
+            .collect()
+    }
+
+    /// Quartiles, Tukey whiskers (the most extreme values within 1.5 IQR of
+    /// the quartiles) and the outliers beyond them.
+    pub fn box_plot(&self) -> BoxPlot {
+        let sorted = sorted(&self.sample);
+        let q1 = percentile(&sorted, 0.25);
+        let q3 = percentile(&sorted, 0.75);
+        let fence = 1.5 * (q3 - q1);
+        let inside: Vec<f64> = sorted
+            .iter()
+            .copied()
+            .filter(|x| *x >= q1 - fence && *x <= q3 + fence)
+            .collect();
+        BoxPlot {
+            q1,
+            median: percentile(&sorted, 0.5),
+            q3,
+            lower_whisker: *inside.first().unwrap_or(&q1),
+            upper_whisker: *inside.last().unwrap_or(&q3),
+            outliers: sorted
+                .iter()
+                .copied()
+                .filter(|x| *x < q1 - fence || *x > q3 + fence)
+                .collect(),
+        }
+    }
+
+    pub fn histogram(&self, rule: BinRule) -> Histogram {
+        let xmin = self.x_min.unwrap();
+        let xmax = self.x_max.unwrap();
+        let sturges = (self.n as f64).log2().ceil() as usize + 1;
+        let bins = match rule {
+            BinRule::Sturges => sturges,
+            BinRule::Fixed(bins) => bins as usize,
+            BinRule::FreedmanDiaconis => {
+                let sorted = sorted(&self.sample);
+                let iqr = percentile(&sorted, 0.75) - percentile(&sorted, 0.25);
+                let width = 2.0 * iqr / (self.n as f64).cbrt();
+                if width > 0.0 {
+                    (((xmax - xmin) / width).ceil() as usize).clamp(1, MAX_FD_BINS)
+                } else {
+                    sturges
+                }
+            }
+        };
+        Histogram::new(&self.sample, xmin, xmax, bins)
+    }
+
+    /// The cumulative mean and its confidence interval after each value of
+    /// the sample, in the order the values were added. The interval needs at
+    /// least two values so the first point is for n = 2.
+    pub fn convergence(&self) -> Vec<ConvergencePoint> {
+        let mut points = Vec::<ConvergencePoint>::with_capacity(self.sample.len());
+        let mut sigma_x = 0.0;
+        let mut sigma_xsq = 0.0;
+        for (i, val) in self.sample.iter().enumerate() {
+            sigma_x += val;
+            sigma_xsq += val.powi(2);
+            if i == 0 {
+                continue;
+            }
+            let n = (i + 1) as f64;
+            let mean = sigma_x / n;
+            let variance = ((sigma_xsq - sigma_x.powi(2) / n) / (n - 1.0)).max(0.0);
+            let t = student_t_quantile(0.5 + CONFIDENCE_LEVEL / 2.0, n - 1.0);
+            let half_width = t * (variance / n).sqrt();
+            points.push(ConvergencePoint {
+                n: (i + 1) as u32,
+                mean,
+                ci_lower: mean - half_width,
+                ci_upper: mean + half_width,
+            });
+        }
+        points
+    }
+}
+
+/// The cumulative mean of the first `n` values of a sample and its
+/// confidence interval
+pub struct ConvergencePoint {
+    n: u32,
+    mean: f64,
+    ci_lower: f64,
+    ci_upper: f64,
+}
+
+impl ConvergencePoint {
+    pub fn n(&self) -> u32 {
+        self.n
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    pub fn ci_lower(&self) -> f64 {
+        self.ci_lower
+    }
+
+    pub fn ci_upper(&self) -> f64 {
+        self.ci_upper
+    }
+}
+
+pub struct Summary {
+    mean: f64,
+    mean_ci_lower: f64,
+    mean_ci_upper: f64,
+    median: f64,
+    median_ci_lower: f64,
+    median_ci_upper: f64,
+}
+
+impl Summary {
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    pub fn mean_ci_lower(&self) -> f64 {
+        self.mean_ci_lower
+    }
+
+    pub fn mean_ci_upper(&self) -> f64 {
+        self.mean_ci_upper
+    }
+
+    pub fn median(&self) -> f64 {
+        self.median
+    }
+
+    pub fn median_ci_lower(&self) -> f64 {
+        self.median_ci_lower
+    }
+
+    pub fn median_ci_upper(&self) -> f64 {
+        self.median_ci_upper
+    }
+}
+
+pub struct BoxPlot {
+    q1: f64,
+    median: f64,
+    q3: f64,
+    lower_whisker: f64,
+    upper_whisker: f64,
+    outliers: Vec<f64>,
+}
+
+impl BoxPlot {
+    pub fn q1(&self) -> f64 {
+        self.q1
+    }
+
+    pub fn median(&self) -> f64 {
+        self.median
+    }
+
+    pub fn q3(&self) -> f64 {
+        self.q3
+    }
+
+    pub fn lower_whisker(&self) -> f64 {
+        self.lower_whisker
+    }
+
+    pub fn upper_whisker(&self) -> f64 {
+        self.upper_whisker
+    }
+
+    pub fn outliers(&self) -> &Vec<f64> {
+        &self.outliers
+    }
+}
+
+/// The most bins that the Freedman–Diaconis rule may pick. A sample with an
+/// IQR close to zero would otherwise ask for millions of bins.
+const MAX_FD_BINS: usize = 1000;
+
+/// The rule used to pick the number of bins of a histogram
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum BinRule {
+    FreedmanDiaconis,
+    Sturges,
+    Fixed(u16),
+}
+
+/// A histogram with equal width bins. There is one more edge than there are
+/// counts.
+pub struct Histogram {
+    edges: Vec<f64>,
+    counts: Vec<u32>,
+    n: u32,
+}
+
+impl Histogram {
+    /// A histogram has at least one bin
+    pub fn new(sample: &[f64], xmin: f64, xmax: f64, bins: usize) -> Histogram {
+        let bins = bins.max(1);
+        let width = (xmax - xmin) / bins as f64;
+        let edges: Vec<f64> = (0..=bins).map(|i| xmin + width * i as f64).collect();
+        let mut counts = vec![0; bins];
+        for x in sample {
+            let bin = if width > 0.0 {
+                (((x - xmin) / width) as usize).min(bins - 1)
+            } else {
+                0
+            };
+            counts[bin] += 1;
+        }
+        Histogram {
+            edges,
+            counts,
+            n: sample.len() as u32,
+        }
+    }
+
+    pub fn edges(&self) -> &Vec<f64> {
+        &self.edges
+    }
+
+    pub fn counts(&self) -> &Vec<u32> {
+        &self.counts
+    }
+
+    /// The count of a bin normalized so that the area of the histogram is 1.0
+    pub fn density(&self, bin: usize) -> Density {
+        let width = self.edges[bin + 1] - self.edges[bin];
+        if width == 0.0 {
+            return 0.0;
+        }
+        self.counts[bin] as f64 / (self.n as f64 * width)
+    }
 }
 
 pub struct Kde<'a, K: Kernel> {
@@ -103,7 +517,7 @@ pub struct Kde<'a, K: Kernel> {
 }
 
 impl<'a, K: Kernel> Kde<'a, K> {
-    pub fn new(kernel: K, bandwidth: f64, sample: &Vec<f64>) -> Kde<K> {
+    pub fn new(kernel: K, bandwidth: f64, sample: &Vec<f64>) -> Kde<'_, K> {
         Kde {
             kernel,
             bandwidth,
@@ -115,15 +529,15 @@ impl<'a, K: Kernel> Kde<'a, K> {
     fn kernel(&self, x: f64) -> f64 {
         let mut sum: f64 = 0.0;
         for xi in self.sample {
-            sum += self.kernel.kernel((x - xi) as f64 / self.bandwidth);
+            sum += self.kernel.kernel((x - xi) / self.bandwidth);
         }
 
-        return sum;
+        sum
     }
 
     pub fn estimate(&self, x: f64) -> f64 {
         let sigma_kx = self.kernel(x);
-        return sigma_kx / (self.n as f64 * self.bandwidth);
+        sigma_kx / (self.n as f64 * self.bandwidth)
     }
 }
 
@@ -137,7 +551,7 @@ pub trait Kernel {
 impl Kernel for GaussianKernel {
     fn kernel(&self, x: f64) -> f64 {
         let exp_sqrt = (x.powi(2)).exp().sqrt();
-        let two_pi_sqrt = (2 as f64 * std::f32::consts::PI as f64).sqrt();
+        let two_pi_sqrt = (2_f64 * std::f32::consts::PI as f64).sqrt();
         (exp_sqrt * two_pi_sqrt).recip()
     }
 }
@@ -156,6 +570,12 @@ pub struct BvAnalysis {
     y_max: f64,
 }
 
+impl Default for BvAnalysis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl BvAnalysis {
     pub fn new() -> BvAnalysis {
         BvAnalysis {
@@ -206,6 +626,19 @@ impl BvAnalysis {
         self.y_max = (slope * self.x_max.unwrap()) + y_int;
     }
 
+    pub fn slope(&self) -> f64 {
+        self.sn / self.sd
+    }
+
+    pub fn intercept(&self) -> f64 {
+        self.y_bar - (self.slope() * self.x_bar)
+    }
+
+    /// The distance of (x, y) above the trendline
+    pub fn residual(&self, x: f64, y: f64) -> f64 {
+        y - (self.slope() * x + self.intercept())
+    }
+
     pub fn trendline(&self) -> TrendLine {
         TrendLine::new(
             self.x_min.unwrap(),
@@ -273,6 +706,12 @@ pub struct KdeDataSet {
     points: Vec<KdeDataPoint>,
 }
 
+impl Default for KdeDataSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl KdeDataSet {
     pub fn new() -> KdeDataSet {
         KdeDataSet {
@@ -287,4 +726,945 @@ impl KdeDataSet {
     pub fn points(&self) -> &Vec<KdeDataPoint> {
         &self.points
     }
+
+    /// The x values of the local maxima of the density
+    pub fn peaks(&self) -> Vec<KdeXVal> {
+        self.points
+            .windows(3)
+            .filter(|w| w[1].density > w[0].density && w[1].density >= w[2].density)
+            .map(|w| w[1].x_val)
+            .collect()
+    }
+}
+
+const BOOTSTRAP_RESAMPLES: usize = 1000;
+const BOOTSTRAP_SEED: u64 = 0x2545_f491_4f6c_dd1d;
+const CONFIDENCE_LEVEL: f64 = 0.95;
+
+const CV_THRESHOLD: f64 = 0.1;
+const AUTOCORRELATION_THRESHOLD: f64 = 0.5;
+const TREND_P_VALUE_THRESHOLD: f64 = 0.01;
+const BIMODALITY_THRESHOLD: f64 = 5.0 / 9.0;
+
+/// A small xorshift generator used to draw bootstrap resamples. It is seeded
+/// with a constant so that the generated files are reproducible.
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    pub fn new(seed: u64) -> XorShift {
+        XorShift { state: seed.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    pub fn next_index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+}
+
+/// The mean of the `window` values up to and including each value of the
+/// sample. The first values are averaged over the values seen so far.
+pub fn moving_average(sample: &[f64], window: usize) -> Vec<f64> {
+    let mut averages = Vec::<f64>::with_capacity(sample.len());
+    let mut sum = 0.0;
+    for (i, val) in sample.iter().enumerate() {
+        sum += val;
+        if i >= window {
+            sum -= sample[i - window];
+        }
+        averages.push(sum / (i + 1).min(window) as f64);
+    }
+    averages
+}
+
+/// Returns the value at the `p` quantile (0.0 - 1.0) of an already sorted
+/// sample, interpolating linearly between the closest ranks. Returns NaN
+/// for an empty sample.
+pub fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return f64::NAN;
+    }
+    let rank = p * (sorted.len() - 1) as f64;
+    let lo = rank.floor() as usize;
+    let hi = rank.ceil() as usize;
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+}
+
+/// Returns a sorted copy of the sample. NaN values are ordered with
+/// `f64::total_cmp` so that they never cause a panic.
+pub fn sorted(sample: &[f64]) -> Vec<f64> {
+    let mut sorted = sample.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    sorted
+}
+
+fn resample_mean(sample: &[f64], rng: &mut XorShift) -> f64 {
+    let mut sum = 0.0;
+    for _ in 0..sample.len() {
+        sum += sample[rng.next_index(sample.len())];
+    }
+    sum / sample.len() as f64
+}
+
+//https://en.wikipedia.org/wiki/Lanczos_approximation
+pub fn ln_gamma(x: f64) -> f64 {
+    const COEFFS: [f64; 6] = [
+        76.180_091_729_471_46,
+        -86.505_320_329_416_77,
+        24.014_098_240_830_91,
+        -1.231_739_572_450_155,
+        0.120_865_097_386_617_9e-2,
+        -0.539_523_938_495_3e-5,
+    ];
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+    let mut ser = 1.000_000_000_190_015;
+    let mut y = x;
+    for c in &COEFFS {
+        y += 1.0;
+        ser += c / y;
+    }
+    -tmp + (2.506_628_274_631_000_5 * ser / x).ln()
+}
+
+// Continued fraction for the incomplete beta function (Numerical Recipes 6.4)
+fn beta_cf(a: f64, b: f64, x: f64) -> f64 {
+    const MAX_ITER: u32 = 200;
+    const EPS: f64 = 3.0e-14;
+    const FPMIN: f64 = 1.0e-300;
+
+    let qab = a + b;
+    let qap = a + 1.0;
+    let qam = a - 1.0;
+    let mut c = 1.0;
+    let mut d = 1.0 - qab * x / qap;
+    if d.abs() < FPMIN {
+        d = FPMIN;
+    }
+    d = d.recip();
+    let mut h = d;
+    for m in 1..=MAX_ITER {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < FPMIN {
+            d = FPMIN;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < FPMIN {
+            c = FPMIN;
+        }
+        d = d.recip();
+        h *= d * c;
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < FPMIN {
+            d = FPMIN;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < FPMIN {
+            c = FPMIN;
+        }
+        d = d.recip();
+        let del = d * c;
+        h *= del;
+        if (del - 1.0).abs() < EPS {
+            break;
+        }
+    }
+    h
+}
+
+/// The regularized incomplete beta function I_x(a, b)
+pub fn inc_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let bt = (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    if x < (a + 1.0) / (a + b + 2.0) {
+        bt * beta_cf(a, b, x) / a
+    } else {
+        1.0 - bt * beta_cf(b, a, 1.0 - x) / b
+    }
+}
+
+/// Cumulative distribution function of Student's t distribution with `df`
+/// degrees of freedom.
+pub fn student_t_cdf(t: f64, df: f64) -> f64 {
+    let tail = 0.5 * inc_beta(df / 2.0, 0.5, df / (df + t * t));
+    if t > 0.0 {
+        1.0 - tail
+    } else {
+        tail
+    }
+}
+
+/// Inverse of the Student's t cumulative distribution function, found by
+/// bisection on `student_t_cdf`.
+pub fn student_t_quantile(p: f64, df: f64) -> f64 {
+    let (mut lo, mut hi) = (-1e3, 1e3);
+    for _ in 0..100 {
+        let mid = (lo + hi) / 2.0;
+        if student_t_cdf(mid, df) < p {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+//Complementary error function with fractional error < 1.2e-7 (Numerical Recipes 6.2)
+pub fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let r = t
+        * (-z * z - 1.265_512_23
+            + t * (1.000_023_68
+                + t * (0.374_091_96
+                    + t * (0.096_784_18
+                        + t * (-0.186_288_06
+                            + t * (0.278_868_07
+                                + t * (-1.135_203_98
+                                    + t * (1.488_515_87
+                                        + t * (-0.822_152_23 + t * 0.170_872_77)))))))))
+            .exp();
+    if x >= 0.0 {
+        r
+    } else {
+        2.0 - r
+    }
+}
+
+/// Cumulative distribution function of the standard normal distribution.
+pub fn normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / std::f64::consts::SQRT_2)
+}
+
+//Inverse of the standard normal CDF with relative error < 1.15e-9
+//https://web.archive.org/web/20151030215612/http://home.online.no/~pjacklam/notes/invnorm/
+pub fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - P_LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+/// Two sided p-value of Welch's unequal variances t-test.
+pub fn welch_t_test(a: &UvAnalysis, b: &UvAnalysis) -> f64 {
+    let va = a.variance() / a.n as f64;
+    let vb = b.variance() / b.n as f64;
+    let se = (va + vb).sqrt();
+    if se == 0.0 {
+        return if a.mean() == b.mean() { 1.0 } else { 0.0 };
+    }
+    let t = (a.mean() - b.mean()) / se;
+    let df = (va + vb).powi(2) / (va.powi(2) / (a.n - 1) as f64 + vb.powi(2) / (b.n - 1) as f64);
+    inc_beta(df / 2.0, 0.5, df / (df + t * t))
+}
+
+/// Two sided p-value of the Mann-Whitney U test using the normal
+/// approximation with a tie and continuity correction.
+pub fn mann_whitney_u(a: &UvAnalysis, b: &UvAnalysis) -> f64 {
+    let mut pooled: Vec<(f64, bool)> = a.sample.iter().map(|x| (*x, true)).collect();
+    pooled.extend(b.sample.iter().map(|x| (*x, false)));
+    pooled.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+    let n1 = a.n as f64;
+    let n2 = b.n as f64;
+    let n = n1 + n2;
+    let mut rank_sum_a = 0.0;
+    let mut tie_sum = 0.0;
+    let mut i = 0;
+    while i < pooled.len() {
+        let mut j = i;
+        while j + 1 < pooled.len() && pooled[j + 1].0 == pooled[i].0 {
+            j += 1;
+        }
+        // Tied values share the average of the ranks they span
+        let rank = (i + j + 2) as f64 / 2.0;
+        let ties = (j - i + 1) as f64;
+        tie_sum += ties.powi(3) - ties;
+        for p in &pooled[i..=j] {
+            if p.1 {
+                rank_sum_a += rank;
+            }
+        }
+        i = j + 1;
+    }
+
+    let u = rank_sum_a - n1 * (n1 + 1.0) / 2.0;
+    let mu = n1 * n2 / 2.0;
+    let sigma = (n1 * n2 / 12.0 * ((n + 1.0) - tie_sum / (n * (n - 1.0)))).sqrt();
+    if sigma == 0.0 {
+        return 1.0;
+    }
+    let z = ((u - mu).abs() - 0.5).max(0.0) / sigma;
+    (2.0 * (1.0 - normal_cdf(z))).min(1.0)
+}
+
+/// Cohen's d using the pooled standard deviation of both samples.
+pub fn cohens_d(a: &UvAnalysis, b: &UvAnalysis) -> f64 {
+    let na = a.n as f64;
+    let nb = b.n as f64;
+    let pooled = (((na - 1.0) * a.variance() + (nb - 1.0) * b.variance()) / (na + nb - 2.0)).sqrt();
+    (a.mean() - b.mean()) / pooled
+}
+
+/// Percentile bootstrap confidence interval for the ratio of the means of
+/// `a` over `b`. Resamples whose ratio is not finite (a zero mean of `b`)
+/// are dropped; if none are left both bounds are NaN.
+pub fn bootstrap_ratio_ci(a: &UvAnalysis, b: &UvAnalysis, level: f64) -> (f64, f64) {
+    let mut rng = XorShift::new(BOOTSTRAP_SEED);
+    let mut ratios = Vec::<f64>::with_capacity(BOOTSTRAP_RESAMPLES);
+    for _ in 0..BOOTSTRAP_RESAMPLES {
+        let mean_a = resample_mean(&a.sample, &mut rng);
+        let mean_b = resample_mean(&b.sample, &mut rng);
+        let ratio = mean_a / mean_b;
+        if ratio.is_finite() {
+            ratios.push(ratio);
+        }
+    }
+    let ratios = sorted(&ratios);
+    let alpha = (1.0 - level) / 2.0;
+    (percentile(&ratios, alpha), percentile(&ratios, 1.0 - alpha))
+}
+
+/// The statistical comparison of the mean time per iteration of two
+/// functions in the same group.
+pub struct Comparison {
+    fn_a: String,
+    fn_b: String,
+    ratio: f64,
+    ci_lower: f64,
+    ci_upper: f64,
+    t_p_value: f64,
+    u_p_value: f64,
+    effect_size: f64,
+}
+
+impl Comparison {
+    /// Compares two samples. Returns None if either sample has fewer than
+    /// two measurements.
+    pub fn new(a: &UvAnalysis, b: &UvAnalysis) -> Option<Comparison> {
+        if a.n < 2 || b.n < 2 {
+            return None;
+        }
+        let (ci_lower, ci_upper) = bootstrap_ratio_ci(a, b, CONFIDENCE_LEVEL);
+        Some(Comparison {
+            fn_a: a.fn_name.clone(),
+            fn_b: b.fn_name.clone(),
+            ratio: a.mean() / b.mean(),
+            ci_lower,
+            ci_upper,
+            t_p_value: welch_t_test(a, b),
+            u_p_value: mann_whitney_u(a, b),
+            effect_size: cohens_d(a, b),
+        })
+    }
+
+    pub fn fn_a(&self) -> &String {
+        &self.fn_a
+    }
+
+    pub fn fn_b(&self) -> &String {
+        &self.fn_b
+    }
+
+    /// The mean time of `fn_a` over the mean time of `fn_b`. A ratio below
+    /// 1.0 means that `fn_a` is faster.
+    pub fn ratio(&self) -> f64 {
+        self.ratio
+    }
+
+    /// The lower bound of the 95% bootstrap confidence interval of the ratio
+    pub fn ci_lower(&self) -> f64 {
+        self.ci_lower
+    }
+
+    /// The upper bound of the 95% bootstrap confidence interval of the ratio
+    pub fn ci_upper(&self) -> f64 {
+        self.ci_upper
+    }
+
+    /// Two sided p-value of Welch's t-test
+    pub fn t_p_value(&self) -> f64 {
+        self.t_p_value
+    }
+
+    /// Two sided p-value of the Mann-Whitney U test
+    pub fn u_p_value(&self) -> f64 {
+        self.u_p_value
+    }
+
+    /// Cohen's d
+    pub fn effect_size(&self) -> f64 {
+        self.effect_size
+    }
+}
+
+/// The geometric mean of the ratios of the mean times per iteration of
+/// pairs of samples, e.g. two functions across all the groups of a suite or
+/// the same function in two runs.
+pub struct SuiteScore {
+    numerator: String,
+    denominator: String,
+    pairs: usize,
+    geomean: f64,
+    ci_lower: f64,
+    ci_upper: f64,
+}
+
+impl SuiteScore {
+    /// Scores the `(numerator, denominator)` sample pairs. Pairs where either
+    /// sample has fewer than two measurements are ignored. Returns None if
+    /// no pairs are left. The confidence interval is a percentile bootstrap
+    /// that resamples every pair; resampled scores that are not finite are
+    /// dropped.
+    pub fn new(
+        numerator: &str,
+        denominator: &str,
+        pairs: &[(UvAnalysis, UvAnalysis)],
+    ) -> Option<SuiteScore> {
+        let pairs: Vec<&(UvAnalysis, UvAnalysis)> =
+            pairs.iter().filter(|(a, b)| a.n >= 2 && b.n >= 2).collect();
+        if pairs.is_empty() {
+            return None;
+        }
+        let geomean = |ratios: &mut dyn Iterator<Item = f64>| {
+            (ratios.map(f64::ln).sum::<f64>() / pairs.len() as f64).exp()
+        };
+
+        let mut rng = XorShift::new(BOOTSTRAP_SEED);
+        let mut scores = Vec::<f64>::with_capacity(BOOTSTRAP_RESAMPLES);
+        for _ in 0..BOOTSTRAP_RESAMPLES {
+            let score = geomean(&mut pairs.iter().map(|(a, b)| {
+                resample_mean(&a.sample, &mut rng) / resample_mean(&b.sample, &mut rng)
+            }));
+            if score.is_finite() {
+                scores.push(score);
+            }
+        }
+        let scores = sorted(&scores);
+        let alpha = (1.0 - CONFIDENCE_LEVEL) / 2.0;
+
+        Some(SuiteScore {
+            numerator: numerator.to_string(),
+            denominator: denominator.to_string(),
+            pairs: pairs.len(),
+            geomean: geomean(&mut pairs.iter().map(|(a, b)| a.mean() / b.mean())),
+            ci_lower: percentile(&scores, alpha),
+            ci_upper: percentile(&scores, 1.0 - alpha),
+        })
+    }
+
+    pub fn numerator(&self) -> &String {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &String {
+        &self.denominator
+    }
+
+    /// The number of sample pairs that were scored
+    pub fn pairs(&self) -> usize {
+        self.pairs
+    }
+
+    /// The geometric mean of the ratios. A score below 1.0 means that the
+    /// numerator is faster.
+    pub fn geomean(&self) -> f64 {
+        self.geomean
+    }
+
+    /// The lower bound of the 95% bootstrap confidence interval of the score
+    pub fn ci_lower(&self) -> f64 {
+        self.ci_lower
+    }
+
+    /// The upper bound of the 95% bootstrap confidence interval of the score
+    pub fn ci_upper(&self) -> f64 {
+        self.ci_upper
+    }
+}
+
+impl fmt::Display for SuiteScore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} / {}: {:.3}x (95% CI {:.3}x - {:.3}x) over {} pairs",
+            self.numerator,
+            self.denominator,
+            self.geomean,
+            self.ci_lower,
+            self.ci_upper,
+            self.pairs
+        )
+    }
+}
+
+/// The asymptotic complexity models that are fitted to parameter sweeps
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Complexity {
+    Constant,
+    Logarithmic,
+    Linear,
+    Linearithmic,
+    Quadratic,
+}
+
+impl Complexity {
+    pub const ALL: [Complexity; 5] = [
+        Complexity::Constant,
+        Complexity::Logarithmic,
+        Complexity::Linear,
+        Complexity::Linearithmic,
+        Complexity::Quadratic,
+    ];
+
+    /// The growth function f(n) of the model
+    pub fn eval(&self, n: f64) -> f64 {
+        match self {
+            Complexity::Constant => 1.0,
+            Complexity::Logarithmic => n.log2(),
+            Complexity::Linear => n,
+            Complexity::Linearithmic => n * n.log2(),
+            Complexity::Quadratic => n.powi(2),
+        }
+    }
+
+    /// Whether f(n) is defined for the parameter n. The logarithmic models
+    /// are only defined for n > 0.
+    pub fn is_defined(&self, n: f64) -> bool {
+        match self {
+            Complexity::Logarithmic | Complexity::Linearithmic => n > 0.0,
+            _ => n.is_finite(),
+        }
+    }
+}
+
+impl fmt::Display for Complexity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Complexity::Constant => write!(f, "O(1)"),
+            Complexity::Logarithmic => write!(f, "O(log n)"),
+            Complexity::Linear => write!(f, "O(n)"),
+            Complexity::Linearithmic => write!(f, "O(n log n)"),
+            Complexity::Quadratic => write!(f, "O(n²)"),
+        }
+    }
+}
+
+/// A least squares fit of time = coefficient * f(n) to the mean time per
+/// iteration at each parameter n.
+pub struct ComplexityFit {
+    complexity: Complexity,
+    coefficient: f64,
+    rms: f64,
+    r_squared: f64,
+}
+
+impl ComplexityFit {
+    pub fn fit(complexity: Complexity, points: &[(f64, f64)]) -> ComplexityFit {
+        let mut sigma_fy = 0.0;
+        let mut sigma_fsq = 0.0;
+        for (n, y) in points {
+            let f = complexity.eval(*n);
+            sigma_fy += f * y;
+            sigma_fsq += f.powi(2);
+        }
+        let coefficient = if sigma_fsq == 0.0 {
+            0.0
+        } else {
+            sigma_fy / sigma_fsq
+        };
+
+        let k = points.len() as f64;
+        let y_bar = points.iter().map(|(_, y)| y).sum::<f64>() / k;
+        let mut ss_res = 0.0;
+        let mut ss_tot = 0.0;
+        for (n, y) in points {
+            ss_res += (y - coefficient * complexity.eval(*n)).powi(2);
+            ss_tot += (y - y_bar).powi(2);
+        }
+        let r_squared = if ss_tot == 0.0 {
+            1.0
+        } else {
+            1.0 - ss_res / ss_tot
+        };
+
+        ComplexityFit {
+            complexity,
+            coefficient,
+            rms: (ss_res / k).sqrt() / y_bar,
+            r_squared,
+        }
+    }
+
+    /// Fits every model and returns the one with the lowest normalized RMS
+    /// error. Models that are undefined for one of the parameters or whose
+    /// error is not finite (e.g. a zero mean time) are skipped. Returns None
+    /// if there are fewer than two parameters or no model could be fitted.
+    pub fn best(points: &[(f64, f64)]) -> Option<ComplexityFit> {
+        if points.len() < 2 {
+            return None;
+        }
+        Complexity::ALL
+            .iter()
+            .filter(|complexity| points.iter().all(|(n, _)| complexity.is_defined(*n)))
+            .map(|complexity| ComplexityFit::fit(*complexity, points))
+            .filter(|fit| fit.rms.is_finite())
+            .min_by(|a, b| a.rms.total_cmp(&b.rms))
+    }
+
+    pub fn complexity(&self) -> Complexity {
+        self.complexity
+    }
+
+    pub fn coefficient(&self) -> f64 {
+        self.coefficient
+    }
+
+    /// The root mean square error of the fit normalized by the mean time
+    pub fn rms(&self) -> f64 {
+        self.rms
+    }
+
+    pub fn r_squared(&self) -> f64 {
+        self.r_squared
+    }
+
+    pub fn estimate(&self, n: f64) -> f64 {
+        self.coefficient * self.complexity.eval(n)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    fn uv_analysis(fn_name: &str, sample: &[f64]) -> UvAnalysis {
+        let mut uva = UvAnalysis::new(fn_name.to_string());
+        for val in sample {
+            uva.add(*val);
+        }
+        uva
+    }
+
+    #[test]
+    fn test_distributions() {
+        assert!((normal_cdf(1.959_963_985) - 0.975).abs() < 1e-6);
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-6);
+        assert!((student_t_cdf(2.228_138_852, 10.0) - 0.975).abs() < 1e-6);
+        assert!((student_t_cdf(-2.228_138_852, 10.0) - 0.025).abs() < 1e-6);
+        assert!((student_t_quantile(0.975, 10.0) - 2.228_138_852).abs() < 1e-6);
+        assert!((normal_quantile(0.975) - 1.959_963_985).abs() < 1e-8);
+        assert!((normal_quantile(0.01) + 2.326_347_874).abs() < 1e-8);
+        assert!(normal_quantile(0.5).abs() < 1e-12);
+        assert!((student_t_quantile(0.5, 4.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_violin() {
+        let violin = uv_analysis("a", &[10.0, 11.0, 10.5, 9.5]).violin(100);
+        assert_eq!(100, violin.points().len());
+        assert!(violin.points().iter().all(|p| p.density().is_finite()));
+
+        let constant = uv_analysis("b", &[10.0, 10.0, 10.0, 10.0]).violin(100);
+        assert_eq!(1, constant.points().len());
+        assert_eq!(10.0, *constant.points()[0].x_val());
+        assert_eq!(0.0, *constant.points()[0].density());
+    }
+
+    #[test]
+    fn test_convergence() {
+        let sample = [10.0, 11.0, 10.5, 9.5, 10.2, 10.8, 9.9, 10.1];
+        let uva = uv_analysis("a", &sample);
+        let points = uva.convergence();
+        assert_eq!(points.len(), sample.len() - 1);
+        assert_eq!(points[0].n(), 2);
+        assert!((points[0].mean() - 10.5).abs() < 1e-12);
+
+        let last = points.last().unwrap();
+        let half_width = student_t_quantile(0.975, 7.0) * uva.std_dev() / 8f64.sqrt();
+        assert!((last.mean() - uva.mean()).abs() < 1e-12);
+        assert!((last.ci_upper() - last.mean() - half_width).abs() < 1e-9);
+        let (ci_lower, ci_upper) = uva.mean_ci();
+        assert!((last.ci_lower() - ci_lower).abs() < 1e-9);
+        assert!((last.ci_upper() - ci_upper).abs() < 1e-9);
+        assert!(last.ci_upper() - last.ci_lower() < points[0].ci_upper() - points[0].ci_lower());
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let steady = uv_analysis("a", &[10.0, 10.2, 9.9, 10.1, 9.8, 10.0, 10.1, 9.9]);
+        assert!(steady.diagnostics().is_empty());
+
+        let drifting: Vec<f64> = (0..50).map(|i| 10.0 + i as f64 * 0.5).collect();
+        let drifting = uv_analysis("b", &drifting);
+        let (s, p_value) = drifting.mann_kendall();
+        assert!(s > 0.0 && p_value < 0.001);
+        assert!(drifting.lag1_autocorrelation() > 0.5);
+        let warnings = drifting.diagnostics();
+        assert!(warnings.iter().any(|w| w.starts_with("unstable: CV")));
+        assert!(warnings.iter().any(|w| w.starts_with("trend: increasing")));
+
+        let bimodal: Vec<f64> = (0..40)
+            .map(|i| if i % 2 == 0 { 10.0 } else { 20.0 } + (i % 5) as f64 * 0.1)
+            .collect();
+        let bimodal = uv_analysis("c", &bimodal);
+        assert!(bimodal.bimodality_coefficient() > 5.0 / 9.0);
+    }
+
+    #[test]
+    fn test_comparison() {
+        let a = uv_analysis("a", &[10.0, 11.0, 10.5, 9.5, 10.2, 10.8, 9.9, 10.1]);
+        let b = uv_analysis("b", &[20.0, 21.0, 20.5, 19.5, 20.2, 20.8, 19.9, 20.1]);
+        let cmp = Comparison::new(&a, &b).unwrap();
+        assert!((cmp.ratio() - a.mean() / b.mean()).abs() < 1e-12);
+        assert!(cmp.ci_lower() <= cmp.ratio() && cmp.ratio() <= cmp.ci_upper());
+        assert!(cmp.t_p_value() < 0.001);
+        assert!(cmp.u_p_value() < 0.001);
+        assert!(cmp.effect_size() < 0.0);
+
+        let same = Comparison::new(&a, &a).unwrap();
+        assert!((same.t_p_value() - 1.0).abs() < 1e-9);
+        assert!((same.u_p_value() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_comparison_zero_mean() {
+        let a = uv_analysis("a", &[0.0, 0.0, 0.0, 0.0]);
+        let b = uv_analysis("b", &[0.0, 0.0, 0.0, 0.0]);
+        let cmp = Comparison::new(&a, &b).unwrap();
+        assert!(cmp.ratio().is_nan());
+        assert!(cmp.ci_lower().is_nan() && cmp.ci_upper().is_nan());
+
+        let score = SuiteScore::new("a", "b", &[(a, b)]).unwrap();
+        assert!(score.ci_lower().is_nan() && score.ci_upper().is_nan());
+
+        let with_nan = uv_analysis("c", &[1.0, f64::NAN, 2.0, 3.0]);
+        with_nan.median_ci();
+        assert_eq!(
+            3,
+            sorted(with_nan.sample())
+                .iter()
+                .filter(|x| x.is_finite())
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_kde_peaks() {
+        let uva = uv_analysis("a", &[10.0, 10.1, 9.9, 10.0, 20.0, 20.1, 19.9, 20.0]);
+        let peaks = uva.kdensity(500).peaks();
+        assert_eq!(2, peaks.len());
+        assert!((peaks[0] - 10.0).abs() < 0.5);
+        assert!((peaks[1] - 20.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_suite_score() {
+        let pairs = vec![
+            (
+                uv_analysis("a", &[10.0, 11.0, 10.5, 9.5]),
+                uv_analysis("b", &[20.0, 22.0, 21.0, 19.0]),
+            ),
+            (
+                uv_analysis("a", &[40.0, 41.0, 39.0, 40.0]),
+                uv_analysis("b", &[5.0, 5.5, 4.5, 5.0]),
+            ),
+            (uv_analysis("a", &[1.0]), uv_analysis("b", &[1.0])),
+        ];
+        let score = SuiteScore::new("a", "b", &pairs).unwrap();
+        assert_eq!(2, score.pairs());
+        assert!((score.geomean() - 2.0).abs() < 1e-9);
+        assert!(score.ci_lower() <= score.geomean() && score.geomean() <= score.ci_upper());
+        assert!(SuiteScore::new("a", "b", &pairs[2..]).is_none());
+    }
+
+    #[test]
+    fn test_complexity_fit() {
+        let linear: Vec<(f64, f64)> = (1..=8)
+            .map(|n| (n as f64 * 100.0, n as f64 * 250.0))
+            .collect();
+        let fit = ComplexityFit::best(&linear).unwrap();
+        assert_eq!(Complexity::Linear, fit.complexity());
+        assert!((fit.coefficient() - 2.5).abs() < 1e-9);
+        assert!((fit.r_squared() - 1.0).abs() < 1e-9);
+
+        let quadratic: Vec<(f64, f64)> = (1..=8)
+            .map(|n| (n as f64, 3.0 * (n as f64).powi(2)))
+            .collect();
+        assert_eq!(
+            Complexity::Quadratic,
+            ComplexityFit::best(&quadratic).unwrap().complexity()
+        );
+        assert!(ComplexityFit::best(&linear[..1]).is_none());
+    }
+
+    #[test]
+    fn test_complexity_fit_zero_param() {
+        let linear: Vec<(f64, f64)> = (0..=8)
+            .map(|n| (n as f64 * 100.0, 10.0 + n as f64 * 250.0))
+            .collect();
+        let fit = ComplexityFit::best(&linear).unwrap();
+        assert!(fit.rms().is_finite());
+        assert_ne!(Complexity::Logarithmic, fit.complexity());
+        assert_ne!(Complexity::Linearithmic, fit.complexity());
+
+        let zeros = [(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)];
+        assert!(ComplexityFit::best(&zeros).is_none());
+    }
+
+    #[test]
+    fn test_histogram() {
+        let uva = uv_analysis("h", &[1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0, 4.0, 5.0]);
+        let hist = uva.histogram(BinRule::Fixed(4));
+        assert_eq!(&vec![1.0, 2.0, 3.0, 4.0, 5.0], hist.edges());
+        assert_eq!(&vec![1, 2, 3, 3], hist.counts());
+        let area: f64 = (0..4).map(|bin| hist.density(bin)).sum();
+        assert!((area - 1.0).abs() < 1e-9);
+        assert_eq!(5, uva.histogram(BinRule::Sturges).counts().len());
+        assert_eq!(&vec![9], uva.histogram(BinRule::Fixed(0)).counts());
+
+        let mut sample: Vec<f64> = (0..100).map(|i| 1.0 + (i % 2) as f64 * 1e-9).collect();
+        sample.push(1e6);
+        let uva = uv_analysis("fd", &sample);
+        let hist = uva.histogram(BinRule::FreedmanDiaconis);
+        assert_eq!(MAX_FD_BINS, hist.counts().len());
+    }
+
+    #[test]
+    fn test_summary() {
+        let uva = uv_analysis("a", &[10.0, 11.0, 10.5, 9.5, 10.2, 10.8, 9.9, 10.1, 30.0]);
+        let summary = uva.summary();
+        assert!((summary.mean() - uva.mean()).abs() < 1e-12);
+        assert_eq!(summary.median(), 10.2);
+        assert!(
+            summary.mean_ci_lower() < summary.mean() && summary.mean() < summary.mean_ci_upper()
+        );
+        assert!(summary.median_ci_lower() <= summary.median());
+        assert!(summary.median() <= summary.median_ci_upper());
+        assert!(summary.median_ci_upper() < summary.mean_ci_upper());
+    }
+
+    #[test]
+    fn test_box_plot() {
+        let uva = uv_analysis("b", &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 40.0]);
+        let box_plot = uva.box_plot();
+        assert!((box_plot.q1() - 3.25).abs() < 1e-9);
+        assert!((box_plot.median() - 5.5).abs() < 1e-9);
+        assert!((box_plot.q3() - 7.75).abs() < 1e-9);
+        assert_eq!(1.0, box_plot.lower_whisker());
+        assert_eq!(9.0, box_plot.upper_whisker());
+        assert_eq!(&vec![40.0], box_plot.outliers());
+    }
+
+    #[test]
+    fn test_ecdf() {
+        let ecdf = uv_analysis("a", &[3.0, 1.0, 2.0, 2.0]).ecdf();
+        assert_eq!(vec![(1.0, 0.25), (2.0, 0.5), (2.0, 0.75), (3.0, 1.0)], ecdf);
+        assert!(ecdf.windows(2).all(|w| w[0].0 <= w[1].0 && w[0].1 < w[1].1));
+        assert_eq!(1.0, ecdf.last().unwrap().1);
+    }
+
+    #[test]
+    fn test_moving_average() {
+        let sample = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(vec![1.0, 1.5, 2.5, 3.5, 4.5], moving_average(&sample, 2));
+        assert_eq!(vec![1.0, 1.5, 2.0, 2.5, 3.0], moving_average(&sample, 10));
+        assert_eq!(sample.to_vec(), moving_average(&sample, 1));
+    }
+
+    #[test]
+    fn test_qq() {
+        let qq = uv_analysis("a", &[5.0, 1.0, 3.0]).qq();
+        assert_eq!(
+            vec![1.0, 3.0, 5.0],
+            qq.iter().map(|(_, x)| *x).collect::<Vec<f64>>()
+        );
+        // Blom's plotting positions (i + 0.625) / (n + 0.25) are symmetric
+        // around the median
+        assert!(qq[1].0.abs() < 1e-12);
+        assert!((qq[0].0 + qq[2].0).abs() < 1e-9);
+        assert!((normal_cdf(qq[0].0) - 0.625 / 3.25).abs() < 1e-6);
+
+        for p in [0.001, 0.02, 0.3, 0.7, 0.98, 0.999] {
+            assert!((normal_cdf(normal_quantile(p)) - p).abs() < 1e-6);
+            assert!((normal_quantile(p) + normal_quantile(1.0 - p)).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_residual() {
+        let points = [(1.0, 3.5), (2.0, 4.5), (3.0, 7.5), (4.0, 8.5)];
+        let mut bva = BvAnalysis::new();
+        for (x, y) in points {
+            bva.add(x, y);
+        }
+        let sum: f64 = points.iter().map(|(x, y)| bva.residual(*x, *y)).sum();
+        assert!(sum.abs() < 1e-9);
+
+        let mut line = BvAnalysis::new();
+        for x in 1..=4 {
+            line.add(x as f64, 2.0 * x as f64 + 1.0);
+        }
+        assert!(line.residual(5.0, 11.0).abs() < 1e-9);
+        assert!((line.residual(5.0, 12.0) - 1.0).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,271 @@
+//! Machine readable summaries of the computed statistics of every function
+//! in every group.
+//!
+//! # Schema (version 1)
+//!
+//! All times are in nanoseconds. Every function has these fields:
+//!
+//! | Field            | Type       | Description                                          |
+//! |------------------|------------|------------------------------------------------------|
+//! | `group`          | string     | Lowercase benchmark group name                       |
+//! | `function`       | string     | Lowercase function name                              |
+//! | `samples`        | integer    | Number of samples                                    |
+//! | `mean`           | number     | Mean time per iteration                              |
+//! | `median`         | number     | Median time per iteration                            |
+//! | `std_dev`        | number     | Standard deviation of the time per iteration         |
+//! | `ci_lower`       | number     | Lower bound of the 95% confidence interval of `mean` |
+//! | `ci_upper`       | number     | Upper bound of the 95% confidence interval of `mean` |
+//! | `slope`          | number     | Slope of the total time against the iteration count  |
+//! | `intercept`      | number     | Intercept of the total time trendline                |
+//! | `kde_peaks`      | number[]   | Times of the local maxima of the KDE                 |
+//! | `warnings`       | string[]   | Measurement quality warnings                         |
+//!
+//! Numbers that are not finite are written as `null` in JSON and left empty
+//! in CSV.
+//!
+//! The JSON summary is an object with a `schema_version` and a `functions`
+//! array of the objects above. The CSV summary has a header row followed by
+//! one row per function. Its first column is `schema_version` and the
+//! `kde_peaks` and `warnings` columns join their values with `;`.
+
+use crate::json::{json_number, json_string};
+use crate::rawdata::CriterionData;
+use crate::FnName;
+use crate::GroupName;
+
+use std::error::Error;
+use std::fmt::Write as FmtWrite;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+/// Incremented whenever a field is renamed or removed or its meaning changes
+pub const SUMMARY_SCHEMA_VERSION: u32 = 1;
+
+const CSV_HEADER: &str = "schema_version,group,function,samples,mean,median,std_dev,ci_lower,ci_upper,slope,intercept,kde_peaks,warnings";
+const KDE_POINTS: u16 = 500;
+
+/// The computed statistics of a function
+pub struct FnSummary {
+    group: GroupName,
+    function: FnName,
+    samples: u32,
+    mean: f64,
+    median: f64,
+    std_dev: f64,
+    ci_lower: f64,
+    ci_upper: f64,
+    slope: f64,
+    intercept: f64,
+    kde_peaks: Vec<f64>,
+    warnings: Vec<String>,
+}
+
+/// The computed statistics of every function in every group
+pub struct SuiteSummary {
+    functions: Vec<FnSummary>,
+}
+
+impl FnSummary {
+    pub fn group(&self) -> &GroupName {
+        &self.group
+    }
+
+    pub fn function(&self) -> &FnName {
+        &self.function
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    pub fn slope(&self) -> f64 {
+        self.slope
+    }
+
+    pub fn kde_peaks(&self) -> &Vec<f64> {
+        &self.kde_peaks
+    }
+
+    /// The numeric fields in schema order
+    fn numbers(&self) -> [(&str, f64); 7] {
+        [
+            ("mean", self.mean),
+            ("median", self.median),
+            ("std_dev", self.std_dev),
+            ("ci_lower", self.ci_lower),
+            ("ci_upper", self.ci_upper),
+            ("slope", self.slope),
+            ("intercept", self.intercept),
+        ]
+    }
+}
+
+impl SuiteSummary {
+    pub fn new(cdata: &CriterionData) -> SuiteSummary {
+        let functions = cdata
+            .datasets()
+            .into_iter()
+            .filter(|(_, _, cdataset)| cdataset.len() >= 2)
+            .map(|(group, fn_name, cdataset)| {
+                let uva = cdataset.uv_analysis_nanos(fn_name);
+                let bva = cdataset.bv_analysis_nanos();
+                let stats_info = uva.stats_info();
+                FnSummary {
+                    group: group.to_string(),
+                    function: fn_name.to_string(),
+                    samples: uva.n(),
+                    mean: stats_info.mean(),
+                    median: stats_info.median(),
+                    std_dev: stats_info.std_dev(),
+                    ci_lower: stats_info.ci_lower(),
+                    ci_upper: stats_info.ci_upper(),
+                    slope: bva.slope(),
+                    intercept: bva.intercept(),
+                    kde_peaks: uva.kdensity(KDE_POINTS).peaks(),
+                    warnings: stats_info.warnings().clone(),
+                }
+            })
+            .collect();
+        SuiteSummary { functions }
+    }
+
+    pub fn functions(&self) -> &Vec<FnSummary> {
+        &self.functions
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\n");
+        let _ = writeln!(json, "  \"schema_version\": {},", SUMMARY_SCHEMA_VERSION);
+        json.push_str("  \"functions\": [");
+        for (i, fn_summary) in self.functions.iter().enumerate() {
+            json.push_str(if i == 0 { "\n" } else { ",\n" });
+            let _ = write!(
+                json,
+                "    {{\"group\": {}, \"function\": {}, \"samples\": {}",
+                json_string(&fn_summary.group),
+                json_string(&fn_summary.function),
+                fn_summary.samples
+            );
+            for (name, val) in &fn_summary.numbers() {
+                let _ = write!(json, ", \"{}\": {}", name, json_number(*val));
+            }
+            let peaks: Vec<String> = fn_summary
+                .kde_peaks
+                .iter()
+                .map(|p| json_number(*p))
+                .collect();
+            let warnings: Vec<String> =
+                fn_summary.warnings.iter().map(|w| json_string(w)).collect();
+            let _ = write!(
+                json,
+                ", \"kde_peaks\": [{}], \"warnings\": [{}]}}",
+                peaks.join(", "),
+                warnings.join(", ")
+            );
+        }
+        json.push_str("\n  ]\n}\n");
+        json
+    }
+
+    pub fn to_csv(&self) -> Result<String, Box<dyn Error>> {
+        let mut writer = csv::Writer::from_writer(Vec::<u8>::new());
+        self.write_csv_rows(&mut writer)?;
+        Ok(String::from_utf8(writer.into_inner()?)?)
+    }
+
+    fn write_csv_rows<W: Write>(&self, writer: &mut csv::Writer<W>) -> csv::Result<()> {
+        writer.write_record(CSV_HEADER.split(','))?;
+        for fn_summary in &self.functions {
+            let mut record = vec![
+                SUMMARY_SCHEMA_VERSION.to_string(),
+                fn_summary.group.to_string(),
+                fn_summary.function.to_string(),
+                fn_summary.samples.to_string(),
+            ];
+            for (_, val) in &fn_summary.numbers() {
+                record.push(csv_number(*val));
+            }
+            let peaks: Vec<String> = fn_summary
+                .kde_peaks
+                .iter()
+                .map(|p| csv_number(*p))
+                .collect();
+            record.push(peaks.join(";"));
+            record.push(fn_summary.warnings.join(";"));
+            writer.write_record(&record)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    pub fn write_json(&self, file_path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut jsonfile = File::create(file_path)?;
+        write!(jsonfile, "{}", self.to_json())?;
+        Ok(())
+    }
+
+    pub fn write_csv(&self, file_path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut writer = csv::Writer::from_path(file_path)?;
+        self.write_csv_rows(&mut writer)?;
+        Ok(())
+    }
+}
+
+fn csv_number(val: f64) -> String {
+    if val.is_finite() {
+        val.to_string()
+    } else {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::timeunit::TimeUnit;
+
+    #[test]
+    fn test_suite_summary() {
+        let mut cdata = CriterionData::new();
+        for (i, measurement) in [10.0, 20.5, 29.5, 40.0].iter().enumerate() {
+            cdata.insert(
+                "Push".to_string(),
+                "Vec, \"Push\"".to_string(),
+                *measurement,
+                (i + 1) as u32,
+                TimeUnit::NS,
+                None,
+            );
+        }
+        cdata.insert(
+            "Pop".to_string(),
+            "a".to_string(),
+            1.0,
+            1,
+            TimeUnit::MS,
+            None,
+        );
+
+        let summary = SuiteSummary::new(&cdata);
+        assert_eq!(1, summary.functions().len());
+        let fn_summary = &summary.functions()[0];
+        assert!((fn_summary.slope() - 9.9).abs() < 0.1);
+
+        let json = summary.to_json();
+        assert!(json.starts_with("{\n  \"schema_version\": 1,\n  \"functions\": [\n"));
+        assert!(json.contains(
+            "{\"group\": \"push\", \"function\": \"vec, \\\"push\\\"\", \"samples\": 4, \"mean\": "
+        ));
+
+        let csv = summary.to_csv().unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(Some(CSV_HEADER), lines.next());
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("1,push,\"vec, \"\"push\"\"\",4,"));
+        assert_eq!(None, lines.next());
+    }
+}
@@ -0,0 +1,369 @@
+use crate::chart::{Chart, Mark};
+use crate::xml::escape;
+
+use std::error::Error;
+use std::fmt::Write as FmtWrite;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+const SVG_WIDTH: f64 = 640.0;
+const SVG_HEIGHT: f64 = 400.0;
+const MARGIN_TOP: f64 = 40.0;
+const MARGIN_RIGHT: f64 = 20.0;
+const MARGIN_BOTTOM: f64 = 75.0;
+const MARGIN_LEFT: f64 = 80.0;
+const TICK_COUNT: usize = 5;
+const AXIS_COLOR: &str = "#666";
+const GRID_COLOR: &str = "#e5e5e5";
+
+/// A standalone SVG rendering of a chart that needs no JavaScript
+pub struct SvgChart<'a> {
+    chart: &'a Chart,
+    width: f64,
+    height: f64,
+}
+
+/// Maps the values of an axis to pixels
+struct Scale {
+    min: f64,
+    max: f64,
+    step: f64,
+    start: f64,
+    end: f64,
+}
+
+impl<'a> SvgChart<'a> {
+    pub fn new(chart: &'a Chart) -> SvgChart<'a> {
+        SvgChart {
+            chart,
+            width: SVG_WIDTH,
+            height: SVG_HEIGHT,
+        }
+    }
+
+    pub fn set_size(&mut self, width: f64, height: f64) {
+        self.width = width;
+        self.height = height;
+    }
+
+    pub fn to_svg(&self) -> String {
+        let (x_min, x_max) = self.chart.x_range();
+        let (y_min, y_max) = self.chart.y_range();
+        let left = MARGIN_LEFT;
+        let right = self.width - MARGIN_RIGHT;
+        let top = MARGIN_TOP;
+        let bottom = self.height - MARGIN_BOTTOM;
+        let x_scale = Scale::new(x_min, x_max, left, right);
+        let y_scale = Scale::new(y_min, y_max, bottom, top);
+        let clip_id = self.element_id("plot-area");
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\" font-size=\"12\">",
+            w = self.width,
+            h = self.height
+        );
+        let _ = writeln!(svg, "  <title>{}</title>", escape(&self.chart.title()));
+        svg.push_str("  <rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n");
+        let _ = writeln!(
+            svg,
+            "  <defs><clipPath id=\"{}\"><rect x=\"{:.2}\" y=\"{:.2}\" width=\"{:.2}\" height=\"{:.2}\"/></clipPath></defs>",
+            clip_id,
+            left,
+            top,
+            right - left,
+            bottom - top
+        );
+        let _ = writeln!(
+            svg,
+            "  <text x=\"{:.2}\" y=\"24\" text-anchor=\"middle\" font-size=\"14\">{}</text>",
+            self.width / 2.0,
+            escape(&self.chart.title())
+        );
+
+        //Grid lines and tick labels
+        for tick in x_scale.ticks() {
+            let x = x_scale.map(tick);
+            let _ = writeln!(
+                svg,
+                "  <line x1=\"{x:.2}\" y1=\"{:.2}\" x2=\"{x:.2}\" y2=\"{:.2}\" stroke=\"{}\"/>",
+                top,
+                bottom,
+                GRID_COLOR,
+                x = x
+            );
+            let _ = writeln!(
+                svg,
+                "  <text x=\"{:.2}\" y=\"{:.2}\" text-anchor=\"middle\" font-size=\"10\" fill=\"{}\">{}</text>",
+                x,
+                bottom + 15.0,
+                AXIS_COLOR,
+                x_scale.format(tick)
+            );
+        }
+        for tick in y_scale.ticks() {
+            let y = y_scale.map(tick);
+            let _ = writeln!(
+                svg,
+                "  <line x1=\"{:.2}\" y1=\"{y:.2}\" x2=\"{:.2}\" y2=\"{y:.2}\" stroke=\"{}\"/>",
+                left,
+                right,
+                GRID_COLOR,
+                y = y
+            );
+            let _ = writeln!(
+                svg,
+                "  <text x=\"{:.2}\" y=\"{:.2}\" text-anchor=\"end\" dominant-baseline=\"middle\" font-size=\"10\" fill=\"{}\">{}</text>",
+                left - 6.0,
+                y,
+                AXIS_COLOR,
+                y_scale.format(tick)
+            );
+        }
+        let _ = writeln!(
+            svg,
+            "  <path d=\"M{:.2},{:.2}V{:.2}H{:.2}\" fill=\"none\" stroke=\"{}\"/>",
+            left, top, bottom, right, AXIS_COLOR
+        );
+
+        //Axis labels
+        let _ = writeln!(
+            svg,
+            "  <text x=\"{:.2}\" y=\"{:.2}\" text-anchor=\"middle\">{}</text>",
+            (left + right) / 2.0,
+            bottom + 35.0,
+            escape(&self.chart.x_label())
+        );
+        let _ = writeln!(
+            svg,
+            "  <text transform=\"translate(20,{:.2}) rotate(-90)\" text-anchor=\"middle\">{}</text>",
+            (top + bottom) / 2.0,
+            escape(&self.chart.y_label())
+        );
+
+        //Layers and rules
+        let _ = writeln!(svg, "  <g clip-path=\"url(#{})\">", clip_id);
+        for layer in self.chart.layers() {
+            let points: Vec<(f64, f64)> = layer
+                .points()
+                .iter()
+                .map(|(x, y)| (x_scale.map(*x), y_scale.map(*y)))
+                .collect();
+            let color = escape(layer.color());
+            match layer.mark() {
+                Mark::Points => {
+                    let _ = writeln!(svg, "    <g fill=\"{}\">", color);
+                    for (x, y) in &points {
+                        let _ = writeln!(
+                            svg,
+                            "      <circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"2\"/>",
+                            x, y
+                        );
+                    }
+                    svg.push_str("    </g>\n");
+                }
+                Mark::Line => {
+                    let _ = writeln!(
+                        svg,
+                        "    <polyline fill=\"none\" stroke=\"{}\" stroke-width=\"1\" points=\"{}\"/>",
+                        color,
+                        polyline_points(&points)
+                    );
+                }
+                Mark::Area | Mark::StepArea => {
+                    let _ = writeln!(
+                        svg,
+                        "    <path d=\"{}\" fill=\"{c}\" fill-opacity=\"0.3\" stroke=\"{c}\" stroke-width=\"1\"/>",
+                        area_path(&points, y_scale.map(0.0), layer.mark() == Mark::StepArea),
+                        c = color
+                    );
+                }
+            }
+        }
+        for rule in self.chart.rules() {
+            let x = x_scale.map(rule.x());
+            let _ = writeln!(
+                svg,
+                "    <line x1=\"{x:.2}\" y1=\"{:.2}\" x2=\"{x:.2}\" y2=\"{:.2}\" stroke=\"{}\" stroke-dasharray=\"4 4\"><title>{}</title></line>",
+                top,
+                bottom,
+                escape(rule.color()),
+                escape(rule.name()),
+                x = x
+            );
+        }
+        svg.push_str("  </g>\n");
+
+        //Legend
+        let mut x = left;
+        let y = self.height - 15.0;
+        for (fn_name, color) in self.chart.legend() {
+            let _ = writeln!(
+                svg,
+                "  <rect x=\"{:.2}\" y=\"{:.2}\" width=\"10\" height=\"10\" fill=\"{}\"/>",
+                x,
+                y - 9.0,
+                escape(color)
+            );
+            let _ = writeln!(
+                svg,
+                "  <text x=\"{:.2}\" y=\"{:.2}\">{}</text>",
+                x + 14.0,
+                y,
+                escape(fn_name)
+            );
+            x += 30.0 + 7.0 * fn_name.chars().count() as f64;
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// An id that is unique to the chart so that the charts of every group
+    /// can be inlined in one HTML page
+    fn element_id(&self, name: &str) -> String {
+        let id = format!(
+            "{}-{}-{}",
+            self.chart.group(),
+            self.chart.kind().name(),
+            name
+        );
+        id.chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect()
+    }
+
+    pub fn write(&self, file_path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut svgfile = File::create(file_path)?;
+        write!(svgfile, "{}", self.to_svg())?;
+        Ok(())
+    }
+}
+
+impl Scale {
+    /// Extends the range of the values to whole ticks and maps it to the
+    /// pixels from `start` to `end`
+    fn new(min: f64, max: f64, start: f64, end: f64) -> Scale {
+        let (min, max) = if max > min {
+            (min, max)
+        } else {
+            let pad = if min == 0.0 { 1.0 } else { min.abs() * 0.1 };
+            (min - pad, max + pad)
+        };
+        let step = nice_step((max - min) / TICK_COUNT as f64);
+        Scale {
+            min: (min / step).floor() * step,
+            max: (max / step).ceil() * step,
+            step,
+            start,
+            end,
+        }
+    }
+
+    fn map(&self, val: f64) -> f64 {
+        self.start + (val - self.min) / (self.max - self.min) * (self.end - self.start)
+    }
+
+    fn ticks(&self) -> Vec<f64> {
+        let count = ((self.max - self.min) / self.step).round() as usize;
+        (0..=count)
+            .map(|i| self.min + i as f64 * self.step)
+            .collect()
+    }
+
+    /// Formats a tick with as many decimals as the step needs
+    fn format(&self, tick: f64) -> String {
+        let decimals = (-self.step.log10().floor()).max(0.0) as usize;
+        format!("{:.*}", decimals, tick)
+    }
+}
+
+/// The smallest of 1, 2 or 5 times a power of ten that is at least `step`
+fn nice_step(step: f64) -> f64 {
+    let magnitude = 10f64.powf(step.log10().floor());
+    let fraction = step / magnitude;
+    let nice = if fraction <= 1.0 {
+        1.0
+    } else if fraction <= 2.0 {
+        2.0
+    } else if fraction <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+fn polyline_points(points: &[(f64, f64)]) -> String {
+    points
+        .iter()
+        .map(|(x, y)| format!("{:.2},{:.2}", x, y))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// A closed path through the points down to the `baseline` pixel. Steps
+/// hold each y until the x of the next point.
+fn area_path(points: &[(f64, f64)], baseline: f64, steps: bool) -> String {
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return String::new(),
+    };
+    let mut path = format!("M{:.2},{:.2}", first.0, baseline);
+    let mut prev_y: Option<f64> = None;
+    for (x, y) in points {
+        if let (true, Some(prev_y)) = (steps, prev_y) {
+            let _ = write!(path, "L{:.2},{:.2}", x, prev_y);
+        }
+        let _ = write!(path, "L{:.2},{:.2}", x, y);
+        prev_y = Some(*y);
+    }
+    let _ = write!(path, "L{:.2},{:.2}Z", last.0, baseline);
+    path
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::chart::ChartStyle;
+    use crate::rawdata::CriterionData;
+    use crate::timeunit::TimeUnit;
+    use crate::tsxdata::TsxData;
+
+    #[test]
+    fn test_svg_chart() {
+        let mut cdata = CriterionData::new();
+        for (i, measurement) in [10.0, 20.5, 29.5, 40.0].iter().enumerate() {
+            cdata.insert(
+                "Push".to_string(),
+                "Vec<T>".to_string(),
+                *measurement,
+                (i + 1) as u32,
+                TimeUnit::NS,
+                None,
+            );
+        }
+        let mut style = ChartStyle::new();
+        style.set_color("vec<t>", "#123456");
+        let tt_data = cdata.total_time_data(None).unwrap();
+        let chart = Chart::linear_regression("push", tt_data.get("push").unwrap(), &style);
+
+        let svg = SvgChart::new(&chart).to_svg();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"640\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(svg.contains(">push: Linear Regression</text>"));
+        assert!(svg.contains(">Total Sample Time (ns)</text>"));
+        assert!(svg.contains(">Iterations</text>"));
+        assert!(svg.contains(">vec&lt;t&gt;</text>"));
+        assert_eq!(4, svg.matches("<circle ").count());
+        assert!(svg.contains("<polyline fill=\"none\" stroke=\"#123456\""));
+
+        assert_eq!(0.5, nice_step(0.42));
+        assert_eq!(20.0, nice_step(11.0));
+        assert_eq!(
+            "M0.00,10.00L0.00,5.00L2.00,5.00L2.00,8.00L2.00,10.00Z",
+            area_path(&[(0.0, 5.0), (2.0, 8.0)], 10.0, true)
+        );
+    }
+}
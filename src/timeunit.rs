@@ -5,8 +5,11 @@ use std::str::FromStr;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub enum TimeUnit {
-    NS, //NanoSecond
+    S,  //Second
     MS, //MilliSecond
+    US, //MicroSecond
+    NS, //NanoSecond
+    PS, //PicoSecond
 }
 
 #[derive(Debug, Clone)]
@@ -17,9 +20,48 @@ impl Error for ParseTimeUnitError {}
 impl Display for TimeUnit {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            TimeUnit::S => write!(f, "s"),
             TimeUnit::MS => write!(f, "ms"),
+            TimeUnit::US => write!(f, "µs"),
             TimeUnit::NS => write!(f, "ns"),
+            TimeUnit::PS => write!(f, "ps"),
+        }
+    }
+}
+
+impl TimeUnit {
+    /// The number of nanoseconds in one of this unit
+    pub fn nanos(&self) -> f64 {
+        match self {
+            TimeUnit::S => 1_000_000_000.0,
+            TimeUnit::MS => 1_000_000.0,
+            TimeUnit::US => 1_000.0,
+            TimeUnit::NS => 1.0,
+            TimeUnit::PS => 0.001,
+        }
+    }
+
+    /// The largest unit, from seconds down to nanoseconds, that a time in
+    /// nanoseconds is at least one of
+    pub fn for_nanos(nanos: f64) -> TimeUnit {
+        [TimeUnit::S, TimeUnit::MS, TimeUnit::US]
+            .iter()
+            .find(|unit| nanos >= unit.nanos())
+            .copied()
+            .unwrap_or(TimeUnit::NS)
+    }
+
+    /// Converts a measurement in this unit to nanoseconds
+    pub fn to_nanos(&self, val: f64) -> f64 {
+        val * self.nanos()
+    }
+
+    /// Converts a measurement in this unit to the `to` unit
+    pub fn convert(&self, val: f64, to: TimeUnit) -> f64 {
+        if *self == to {
+            return val;
         }
+        val * self.nanos() / to.nanos()
     }
 }
 
@@ -34,8 +76,11 @@ impl FromStr for TimeUnit {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_ascii_lowercase().as_str() {
-            "ns" => Ok(Self::NS),
+            "s" => Ok(Self::S),
             "ms" => Ok(Self::MS),
+            "us" | "µs" | "μs" => Ok(Self::US),
+            "ns" => Ok(Self::NS),
+            "ps" => Ok(Self::PS),
             _ => Err(ParseTimeUnitError(s.to_string())),
         }
     }
@@ -1,6 +1,6 @@
-pub const VERSION: &'static str = env!("CARGO_PKG_VERSION");
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
-pub const TSX_HEADER: &'static str = r###"
+pub const TSX_HEADER: &str = r###"
 /*
    Contains data and React component to render criterion plots.
 
@@ -25,31 +25,106 @@ pub const TSX_HEADER: &'static str = r###"
 ///////////////////////////////////////////////////////////////////////////////////////
 "###;
 
-pub const PLOT_COMPONENTS_IMPORTS: &'static str = r###"
+pub const PLOT_COMPONENTS_IMPORTS: &str = r###"
 import React from 'react';
-import { TimeUnit, UnitsMap, StatsInfo, StatsInfoMap, CriterionPlotProps, CriterionMeanTimePerIterChart, CriterionProbabilityDensityChart, CriterionLinearRegressionChart, SeriesInfo, SeriesInfoMap, DataPoint } from './ccharts"###;
+import { TimeUnit, UnitsMap, StatsInfo, StatsInfoMap, CriterionPlotProps, CriterionMeanTimePerIterChart, CriterionProbabilityDensityChart, CriterionLinearRegressionChart, SeriesInfo, SeriesInfoMap, DataPoint, Comparison, ComparisonMap, CriterionComparisonProps, CriterionComparison, ComplexityInfo, ComplexityInfoMap, CriterionComplexityChart, CriterionCdfChart, BoxPlotInfo, BoxPlotInfoMap, ViolinInfo, ViolinInfoMap, CriterionBoxPlotChart, CriterionViolinChart, CriterionSampleDriftChart, CriterionConvergenceChart, CriterionQQChart, CriterionResidualChart, SummaryInfo, SummaryInfoMap, CriterionSummaryBarChart, RelativeInfo, RelativeInfoMap, BudgetInfo, BudgetsInfo, BudgetsInfoMap } from './ccharts"###;
 
-pub const PLOT_COMPONENTS_TSX: &'static str = r###"
+pub const SUITE_COMPONENTS_IMPORTS: &str = r###"
+import React from 'react';
+import { TimeUnit, MatrixInfo, CriterionHeatmapProps, CriterionHeatmap } from './ccharts"###;
+
+pub const SCORE_IMPORTS: &str = r###"
+import { ScoreInfo } from './ccharts"###;
+
+pub const SUITE_COMPONENTS_TSX: &str = r###"
+export const CriterionSuiteHeatmap = (props: CriterionHeatmapProps) => {
+    return (
+        <CriterionHeatmap matrix={SUITE_MATRIX} {...props} />
+    );
+}
+"###;
+
+pub const PLOT_COMPONENTS_TSX: &str = r###"
 export const CriterionMTPlot = (props: CriterionPlotProps) => {
     return (
-        <CriterionMeanTimePerIterChart unitsMap={MEAN_TIME_DATA_UNITS} seriesInfoMap={SERIES_INFO_MAP} dataMap={MEAN_TIME_DATA_MAP} {...props} />
+        <CriterionMeanTimePerIterChart unitsMap={MEAN_TIME_DATA_UNITS} seriesInfoMap={SERIES_INFO_MAP} dataMap={MEAN_TIME_DATA_MAP} relativeDataMap={RELATIVE_DATA_MAP} relativeMap={RELATIVE_MAP} statsInfoMap={STATS_INFO_MAP} budgetMap={BUDGET_MAP} {...props} />
     );
 }
 
 export const CriterionLRPlot = (props: CriterionPlotProps) => {
     return (
-        <CriterionLinearRegressionChart unitsMap={TOTAL_TIME_DATA_UNITS} seriesInfoMap={SERIES_INFO_MAP} dataMap={TOTAL_TIME_DATA_MAP} {...props} />
+        <CriterionLinearRegressionChart unitsMap={TOTAL_TIME_DATA_UNITS} seriesInfoMap={SERIES_INFO_MAP} dataMap={TOTAL_TIME_DATA_MAP} statsInfoMap={STATS_INFO_MAP} {...props} />
+    );
+}
+
+export const CriterionResidualPlot = (props: CriterionPlotProps) => {
+    return (
+        <CriterionResidualChart unitsMap={TOTAL_TIME_DATA_UNITS} seriesInfoMap={SERIES_INFO_MAP} dataMap={RESIDUAL_DATA_MAP} statsInfoMap={STATS_INFO_MAP} {...props} />
     );
 }
 
 export const CriterionPDFPlot = (props: CriterionPlotProps) => {
     return (
-        <CriterionProbabilityDensityChart unitsMap={MEAN_TIME_DATA_UNITS} seriesInfoMap={SERIES_INFO_MAP} dataMap={PDF_DATA_MAP} statsInfoMap={STATS_INFO_MAP} {...props} />
+        <CriterionProbabilityDensityChart unitsMap={MEAN_TIME_DATA_UNITS} seriesInfoMap={SERIES_INFO_MAP} dataMap={PDF_DATA_MAP} statsInfoMap={STATS_INFO_MAP} budgetMap={BUDGET_MAP} {...props} />
+    );
+}
+
+export const CriterionCmpText = (props: CriterionComparisonProps) => {
+    return (
+        <CriterionComparison comparisonMap={COMPARISON_MAP} {...props} />
+    );
+}
+
+export const CriterionCxPlot = (props: CriterionPlotProps) => {
+    return (
+        <CriterionComplexityChart unitsMap={MEAN_TIME_DATA_UNITS} seriesInfoMap={SERIES_INFO_MAP} dataMap={COMPLEXITY_DATA_MAP} complexityMap={COMPLEXITY_MAP} statsInfoMap={STATS_INFO_MAP} {...props} />
+    );
+}
+
+export const CriterionCDFPlot = (props: CriterionPlotProps) => {
+    return (
+        <CriterionCdfChart unitsMap={MEAN_TIME_DATA_UNITS} seriesInfoMap={SERIES_INFO_MAP} dataMap={CDF_DATA_MAP} statsInfoMap={STATS_INFO_MAP} {...props} />
+    );
+}
+
+export const CriterionDriftPlot = (props: CriterionPlotProps) => {
+    return (
+        <CriterionSampleDriftChart unitsMap={MEAN_TIME_DATA_UNITS} seriesInfoMap={SERIES_INFO_MAP} dataMap={DRIFT_DATA_MAP} statsInfoMap={STATS_INFO_MAP} {...props} />
+    );
+}
+
+export const CriterionConvergencePlot = (props: CriterionPlotProps) => {
+    return (
+        <CriterionConvergenceChart unitsMap={MEAN_TIME_DATA_UNITS} seriesInfoMap={SERIES_INFO_MAP} dataMap={CONVERGENCE_DATA_MAP} statsInfoMap={STATS_INFO_MAP} {...props} />
+    );
+}
+
+export const CriterionQQPlot = (props: CriterionPlotProps) => {
+    return (
+        <CriterionQQChart unitsMap={MEAN_TIME_DATA_UNITS} seriesInfoMap={SERIES_INFO_MAP} dataMap={QQ_DATA_MAP} statsInfoMap={STATS_INFO_MAP} {...props} />
+    );
+}
+
+export const CriterionBoxPlot = (props: CriterionPlotProps) => {
+    return (
+        <CriterionBoxPlotChart unitsMap={MEAN_TIME_DATA_UNITS} boxPlotMap={BOX_PLOT_MAP} statsInfoMap={STATS_INFO_MAP} {...props} />
+    );
+}
+
+export const CriterionSummaryPlot = (props: CriterionPlotProps) => {
+    return (
+        <CriterionSummaryBarChart unitsMap={MEAN_TIME_DATA_UNITS} summaryMap={SUMMARY_MAP} relativeMap={RELATIVE_MAP} statsInfoMap={STATS_INFO_MAP} {...props} />
+    );
+}
+
+export const CriterionViolinPlot = (props: CriterionPlotProps) => {
+    return (
+        <CriterionViolinChart unitsMap={MEAN_TIME_DATA_UNITS} violinMap={VIOLIN_MAP} statsInfoMap={STATS_INFO_MAP} {...props} />
     );
 }
 "###;
 
-pub const CCHARTS_TSX_CODE: &'static str = r###"/*
+pub const CCHARTS_TSX_CODE: &str = r###"/*
    Contains React components to render a criterion plots.
 
    Copyright 2021 "Rahul Singh <rsingh@arrsingh.com>"
@@ -78,6 +153,12 @@ export enum PlotName {
     MeanTimePerIter,
     LinearRegression,
     ProbabilityDensity,
+    Complexity,
+    CumulativeDistribution,
+    SampleDrift,
+    Convergence,
+    QuantileQuantile,
+    Residual,
 }
 
 export enum TimeUnit {
@@ -85,6 +166,8 @@ export enum TimeUnit {
     ms,
     ns,
     ps,
+    // Appended so that the numeric values of the other units are unchanged
+    µs,
 }
 
 export interface TimeUnits {
@@ -103,15 +186,112 @@ interface TrendLine {
     strokeWidth?: number,
 }
 
+interface MeanBand {
+    fill?: string,
+    fillOpacity?: number,
+    stroke?: string,
+    strokeWidth?: number,
+}
+
+export interface RelativeInfo {
+    baseline: string,
+    // null when the ratio is undefined (a zero mean time of the baseline)
+    ratio: number | null,
+    ciLower: number | null,
+    ciUpper: number | null,
+}
+
+export interface BudgetInfo {
+    statistic: string,
+    operator: string,
+    // The limit in nanoseconds
+    limit: number,
+}
+
+export interface ScoreInfo {
+    numerator: string,
+    denominator: string,
+    pairs: number,
+    // null when a pair has a zero mean time and the score is undefined
+    geomean: number | null,
+    ciLower: number | null,
+    ciUpper: number | null,
+}
+
+export interface MatrixInfo {
+    groups: string[],
+    functions: string[],
+    // Mean time per iteration in nanoseconds indexed by group then function,
+    // null if the group has no such function or its mean is not finite
+    means: (number | null)[][],
+    baselines: (string | null)[],
+}
+
+export interface SummaryInfo {
+    mean: number,
+    meanCiLower: number,
+    meanCiUpper: number,
+    median: number,
+    medianCiLower: number,
+    medianCiUpper: number,
+}
+
 export interface StatsInfo {
     mean: number,
+    median: number,
     stdDev: number,
+    ciLower: number,
+    ciUpper: number,
+    warnings: string[],
+}
+
+export interface Comparison {
+    ratio: number | null,
+    ciLower: number | null,
+    ciUpper: number | null,
+    tPValue: number | null,
+    uPValue: number | null,
+    effectSize: number | null,
+}
+
+export interface ComplexityInfo {
+    model: string,
+    coefficient: number,
+    rms: number,
+    rSquared: number,
+}
+
+export interface BoxPlotInfo {
+    q1: number,
+    median: number,
+    q3: number,
+    lowerWhisker: number,
+    upperWhisker: number,
+    outliers: number[],
+}
+
+export interface ViolinInfo {
+    x: number[],
+    density: number[],
 }
 
 export interface SeriesInfo {
     yIndex: string,
     tyIndex: string,
     dyIndex: string,
+    fyIndex: string,
+    hyIndex: string,
+    cyIndex: string,
+    ryIndex: string,
+    myIndex: string,
+    lyIndex: string,
+    uyIndex: string,
+    qyIndex: string,
+    qryIndex: string,
+    eyIndex: string,
+    bmyIndex: string,
+    blyIndex: string,
+    buyIndex: string,
 }
 
 interface Series {
@@ -124,6 +304,10 @@ interface Series {
     areaFill?: string,
     mean?: Mean,
     renderMean?: boolean,
+    meanBand?: MeanBand,
+    renderMeanBand?: boolean,
+    budget?: BudgetMarker,
+    renderBudgets?: boolean,
 }
 
 interface PlotLabel {
@@ -141,6 +325,18 @@ export interface Range {
     max: number,
 }
 
+export interface BudgetMarker {
+    stroke?: string,
+    strokeWidth?: number,
+    strokeDasharray?: string,
+}
+
+export interface PercentileMarker {
+    stroke?: string,
+    strokeWidth?: number,
+    strokeDasharray?: string,
+}
+
 export interface CriterionPlotProps {
     group: string,
     style?: CSSProperties
@@ -153,9 +349,24 @@ export interface CriterionPlotProps {
     timeUnit?: TimeUnit,
     iterMultiplier?: number,
     xrange?: Range,
+    percentiles?: number[],
+    percentileMarker?: PercentileMarker,
+    title?: string,
+    statistic?: SummaryStatistic,
+    relativeTo?: string,
 }
 
+export type SummaryStatistic = 'mean' | 'median';
+
 export type StatsInfoMap = Map<string, Map<string, StatsInfo>>;
+export type ComparisonMap = Map<string, Map<string, Comparison>>;
+export type ComplexityInfoMap = Map<string, Map<string, ComplexityInfo>>;
+export type BoxPlotInfoMap = Map<string, Map<string, BoxPlotInfo>>;
+export type ViolinInfoMap = Map<string, Map<string, ViolinInfo>>;
+export type SummaryInfoMap = Map<string, Map<string, SummaryInfo>>;
+export type RelativeInfoMap = Map<string, Map<string, RelativeInfo>>;
+export type BudgetsInfo = BudgetInfo[];
+export type BudgetsInfoMap = Map<string, Map<string, BudgetsInfo>>;
 export type SeriesInfoMap = Map<string, Map<string, SeriesInfo>>;
 export type UnitsMap = Map<string, TimeUnit>;
 export type DataMap = Map<string, DataPoint[]>;
@@ -164,7 +375,57 @@ interface CriterionChartProps extends CriterionPlotProps {
     dataMap: DataMap,
     seriesInfoMap: SeriesInfoMap,
     statsInfoMap?: StatsInfoMap,
+    complexityMap?: ComplexityInfoMap,
+    relativeDataMap?: DataMap,
+    relativeMap?: RelativeInfoMap,
+    budgetMap?: BudgetsInfoMap,
+    unitsMap: UnitsMap,
+}
+
+export interface CriterionComparisonProps {
+    group: string,
+    fnA: string,
+    fnB: string,
+    style?: CSSProperties,
+    className?: string,
+    precision?: number,
+}
+
+interface CriterionComparisonTextProps extends CriterionComparisonProps {
+    comparisonMap: ComparisonMap,
+}
+
+export type HeatmapMode = 'mean' | 'relative';
+
+export interface HeatmapColors {
+    fast?: string,
+    neutral?: string,
+    slow?: string,
+}
+
+export interface CriterionHeatmapProps {
+    style?: CSSProperties,
+    className?: string,
+    title?: string,
+    mode?: HeatmapMode,
+    groups?: string[],
+    functions?: string[],
+    timeUnit?: TimeUnit,
+    colors?: HeatmapColors,
+    precision?: number,
+}
+
+interface CriterionHeatmapChartProps extends CriterionHeatmapProps {
+    matrix: MatrixInfo,
+}
+
+interface CriterionCategoryChartProps extends CriterionPlotProps {
     unitsMap: UnitsMap,
+    boxPlotMap?: BoxPlotInfoMap,
+    violinMap?: ViolinInfoMap,
+    summaryMap?: SummaryInfoMap,
+    relativeMap?: RelativeInfoMap,
+    statsInfoMap?: StatsInfoMap,
 }
 
 export enum LegendPosition {
@@ -231,17 +492,26 @@ interface LineChartState {
 }
 
 const TIME_UNIT_CONVERT: number[][] = [
-    /* s,  ms, ns, ps */
-    /*  s   */[1, 1000, 1000000000, 1000000000000],
-    /*  ms  */[0.001, 1, 1000000, 1000000000],
-    /*  ns  */[0.000000001, 0.000001, 1, 1000000],
-    /*  ps  */[0.000000000001, 0.000000001, 0.000001, 1]
+    /* s,  ms, ns, ps, µs */
+    /*  s   */[1, 1000, 1000000000, 1000000000000, 1000000],
+    /*  ms  */[0.001, 1, 1000000, 1000000000, 1000],
+    /*  ns  */[0.000000001, 0.000001, 1, 1000, 0.001],
+    /*  ps  */[0.000000000001, 0.000000001, 0.001, 1, 0.000001],
+    /*  µs  */[0.000001, 0.001, 1000, 1000000, 1]
 ];
 
+const convertTime = (val: number, unitFrom: TimeUnit, unitTo: TimeUnit) => {
+    if (unitFrom === unitTo) {
+        return val;
+    }
+    return val * TIME_UNIT_CONVERT[unitFrom][unitTo];
+}
+
 const RECHARTS_DATAMIN: string = 'dataMin';
 const RECHARTS_DATAMAX: string = 'dataMax';
 const SELECTION_BOUNDARY_UNSET: number = -1;
 const REFERENCE_AREA_BOUNDARY_UNSET: string = '';
+const COMPARISON_KEY_SEPARATOR: string = ' vs ';
 
 class CriterionLineChart extends PureComponent<LineChartProps, LineChartState> {
     constructor(props: LineChartProps) {
@@ -455,11 +725,7 @@ class CriterionLineChart extends PureComponent<LineChartProps, LineChartState> {
     }
 
     convertTimeUnit = (val: number, unitFrom: TimeUnit, unitTo: TimeUnit) => {
-        if (unitFrom === unitTo) {
-            return val;
-        }
-        let multiplier = TIME_UNIT_CONVERT[unitFrom][unitTo];
-        return val * multiplier;
+        return convertTime(val, unitFrom, unitTo);
     }
 
     rescaleData = (data: DataPoint[], timeUnits: TimeUnits, iterMultiplier: number = 1) => {
@@ -471,7 +737,8 @@ class CriterionLineChart extends PureComponent<LineChartProps, LineChartState> {
         data.forEach((entry: DataPoint) => {
             let { i, x, ...rest } = entry;
             for (const key in rest) {
-                if (this.props.invertRescale && !key.startsWith('d')) {
+                // Densities (d) and histogram bins (h) are not scaled by the iterMultiplier
+                if (this.props.invertRescale && !key.startsWith('d') && !key.startsWith('h')) {
                     rest[key] = rest[key] / iterMultiplier;
                 } else if (timeUnits && timeUnits.data !== timeUnits.display) {
                     rest[key] = this.convertTimeUnit(rest[key], timeUnits.data, timeUnits.display);
@@ -565,7 +832,85 @@ const renderErrorDiv = (group: string) => {
     );
 }
 
-const renderTrendLine = (seriesName: string, trendLine: TrendLine | undefined, yDataKey: string | null) => {
+const getWarnings = (group: string, series: Series[], statsInfoMap?: StatsInfoMap) => {
+    let warnings: string[] = [];
+    let groupStatsMap = statsInfoMap?.get(group);
+    if (!groupStatsMap) {
+        return warnings;
+    }
+    series.forEach((s: Series) => {
+        let statsInfo = groupStatsMap?.get(s.name);
+        if (statsInfo) {
+            statsInfo.warnings.forEach((w: string) => warnings.push(s.name + ": " + w));
+        }
+    });
+    return warnings;
+}
+
+const WARNING_BADGE_STYLE: CSSProperties = {
+    marginLeft: '8px',
+    padding: '0 6px',
+    borderRadius: '8px',
+    backgroundColor: '#f0ad4e',
+    color: 'white',
+    fontSize: '0.8em',
+    cursor: 'help',
+};
+
+const renderTitle = (title: string | undefined, group: string, series: Series[], statsInfoMap?: StatsInfoMap) => {
+    if (!title) {
+        return null;
+    }
+    let warnings = getWarnings(group, series, statsInfoMap);
+    return (
+        <div style={{ textAlign: 'center', fontWeight: 'bold' }}>
+            {title}
+            {warnings.length > 0 ?
+                <span style={WARNING_BADGE_STYLE} title={warnings.join('\n')}>
+                    {'\u26a0 ' + warnings.length}
+                </span> : null}
+        </div>
+    );
+}
+
+// Returns the relative times of a group if they were generated against the
+// relativeTo baseline
+const getRelativeInfo = (relativeMap: RelativeInfoMap | undefined, group: string, relativeTo: string) => {
+    let groupRelativeMap = relativeMap?.get(group);
+    if (!groupRelativeMap) {
+        return null;
+    }
+    let baseline = groupRelativeMap.values().next().value?.baseline;
+    if (!baseline || baseline.toLowerCase() !== relativeTo.toLowerCase()) {
+        return null;
+    }
+    return groupRelativeMap;
+}
+
+const renderRelativeErrorDiv = (group: string, relativeTo: string) => {
+    return (
+        <ErrorDiv message={'Could not find data relative to ' + relativeTo + ' for Criterion Group: ' + group + '. Please generate the tsx files with --relative-to ' + relativeTo} />
+    );
+}
+
+export const formatRelative = (ratio: number | null, baseline: string, precision: number = 2) => {
+    if (ratio === null) {
+        return 'n/a relative to ' + baseline;
+    }
+    if (ratio < 1) {
+        return (1 / ratio).toFixed(precision) + '× faster than ' + baseline;
+    }
+    return ratio.toFixed(precision) + '× slower than ' + baseline;
+}
+
+const getRelativeLabelValue = (relativeTo: string, suppliedVal?: string) => {
+    if (suppliedVal) {
+        return suppliedVal;
+    }
+    return "Time Relative to " + relativeTo + " (×)";
+}
+
+const renderTrendLine = (seriesName: string, trendLine: TrendLine | undefined, yDataKey: string | null, label: string = 'LR') => {
     if (!trendLine) {
         trendLine = {
             strokeWidth: 1
@@ -583,7 +928,7 @@ const renderTrendLine = (seriesName: string, trendLine: TrendLine | undefined, y
             strokeWidth={trendLine.strokeWidth}
             yAxisId="left"
             dot={false}
-            name={'LR (' + seriesName + ')'}
+            name={label + ' (' + seriesName + ')'}
             activeDot={false}
             animationDuration={300} />
     );
@@ -617,6 +962,87 @@ const renderArea = (series: Series, yDataKey: string | null, yAxisId: string = "
     );
 }
 
+const renderHistogram = (series: Series, yDataKey: string | null, yAxisId: string = "left") => {
+    if (!yDataKey) {
+        return null;
+    }
+
+    return (
+        <Area
+            yAxisId={yAxisId}
+            type="stepAfter"
+            dataKey={yDataKey}
+            dot={false}
+            name={"Histogram (" + series.name + ")"}
+            connectNulls
+            fill={series.areaFill}
+            stroke={series.stroke}
+            strokeWidth={series.strokeWidth ? series.strokeWidth : 1}
+            isAnimationActive={false}
+        />
+    );
+}
+
+const renderConfidenceBand = (series: Series, lowerDataKey: string | null, upperDataKey: string | null, yAxisId: string = "left") => {
+    if (!lowerDataKey || !upperDataKey) {
+        return null;
+    }
+
+    return (
+        <Area
+            yAxisId={yAxisId}
+            type="monotone"
+            dataKey={(d: DataPoint) => [d[lowerDataKey], d[upperDataKey]]}
+            dot={false}
+            name={"CI (" + series.name + ")"}
+            connectNulls
+            fill={series.areaFill}
+            fillOpacity={0.3}
+            stroke="none"
+            isAnimationActive={false}
+        />
+    );
+}
+
+const renderMeanBand = (series: Series, meanDataKey: string | null, lowerDataKey: string | null, upperDataKey: string | null) => {
+    if (!series.renderMeanBand || !meanDataKey || !lowerDataKey || !upperDataKey) {
+        return null;
+    }
+
+    let fill = series.meanBand?.fill ? series.meanBand.fill : (series.areaFill ? series.areaFill : series.stroke);
+    let stroke = series.meanBand?.stroke ? series.meanBand.stroke : series.stroke;
+    return [
+        <Area
+            key={series.name + '-band'}
+            yAxisId="left"
+            type="monotone"
+            dataKey={(d: DataPoint) => [d[lowerDataKey], d[upperDataKey]]}
+            dot={false}
+            activeDot={false}
+            name={"Band (" + series.name + ")"}
+            connectNulls
+            fill={fill}
+            fillOpacity={series.meanBand?.fillOpacity ? series.meanBand.fillOpacity : 0.2}
+            stroke="none"
+            isAnimationActive={false}
+        />,
+        <Line
+            key={series.name + '-mean'}
+            yAxisId="left"
+            type="monotone"
+            dataKey={meanDataKey}
+            dot={false}
+            activeDot={false}
+            name={"Mean (" + series.name + ")"}
+            connectNulls
+            stroke={stroke}
+            strokeWidth={series.meanBand?.strokeWidth ? series.meanBand.strokeWidth : 1}
+            strokeDasharray="5 5"
+            isAnimationActive={false}
+        />,
+    ];
+}
+
 const renderPlotLine = (series: Series, yDataKey: string | null, yAxisId: string = "left", defaultStrokeWidth: number = 1) => {
     if (!yDataKey) {
         return null;
@@ -681,7 +1107,7 @@ const getTrendLineDataKey = (seriesInfoMap: SeriesInfoMap, series: Series, group
     return seriesNameMap.tyIndex;
 }
 
-const getSeriesLineDataKey = (seriesInfoMap: SeriesInfoMap, series: Series, group: string, yIndexName: 'yIndex' | 'dyIndex' = 'yIndex') => {
+const getSeriesLineDataKey = (seriesInfoMap: SeriesInfoMap, series: Series, group: string, yIndexName: 'yIndex' | 'dyIndex' | 'fyIndex' | 'hyIndex' | 'cyIndex' | 'ryIndex' | 'myIndex' | 'lyIndex' | 'uyIndex' | 'qyIndex' | 'qryIndex' | 'eyIndex' | 'bmyIndex' | 'blyIndex' | 'buyIndex' = 'yIndex') => {
     let groupSeriesMap = seriesInfoMap.get(group);
     if (!groupSeriesMap) {
         console.error("Invalid Series Group: ", group);
@@ -725,7 +1151,11 @@ const renderReferenceLine = (group: string, seriesName: string, refLineType: "me
     }
 }
 
-const getYDataKeys = (seriesInfoMap: SeriesInfoMap, series: Series[], group: string, yIndexName: 'yIndex' | 'dyIndex' = 'yIndex') => {
+const hasDataKeys = (data: DataPoint[], yDataKeys: string[]) => {
+    return data.some((d: DataPoint) => yDataKeys.some((yDataKey: string) => d[yDataKey] !== undefined));
+}
+
+const getYDataKeys = (seriesInfoMap: SeriesInfoMap, series: Series[], group: string, yIndexName: 'yIndex' | 'dyIndex' | 'hyIndex' | 'cyIndex' = 'yIndex') => {
     let yDataKeys: string[] = [];
     series.forEach((s: Series) => {
         let groupSeriesMap = seriesInfoMap.get(group);
@@ -764,13 +1194,33 @@ const getXLabelValue = (unit: TimeUnit | null, iterMultiplier: number = 1, plotN
                 return "Iterations (" + mulToString(iterMultiplier) + ")";
             }
         }
-        case PlotName.MeanTimePerIter: {
+        case PlotName.MeanTimePerIter:
+        case PlotName.Residual: {
             if (iterMultiplier === 1) {
                 return "Iterations";
             } else {
                 return "Iterations (" + mulToString(iterMultiplier) + ")";
             }
         }
+        case PlotName.Complexity: {
+            return "Input Size";
+        }
+        case PlotName.CumulativeDistribution: {
+            if (unit === 0 || unit) {
+                return "Mean Time Per Iteration (" + TimeUnit[unit] + ")";
+            } else {
+                return "Mean Time Per Iteration"
+            }
+        }
+        case PlotName.SampleDrift: {
+            return "Sample";
+        }
+        case PlotName.Convergence: {
+            return "Samples";
+        }
+        case PlotName.QuantileQuantile: {
+            return "Theoretical Normal Quantiles";
+        }
     }
 
 }
@@ -815,6 +1265,32 @@ const getYLabelValue = (unit: TimeUnit | null, plotName: PlotName, suppliedVal?:
         case PlotName.ProbabilityDensity: {
             return "Density"
         }
+        case PlotName.Complexity: {
+            if (unit === 0 || unit) {
+                return "Mean Time Per Iteration (" + TimeUnit[unit] + ")";
+            } else {
+                return "Mean Time Per Iteration";
+            }
+        }
+        case PlotName.CumulativeDistribution: {
+            return "Cumulative Probability";
+        }
+        case PlotName.Residual: {
+            if (unit === 0 || unit) {
+                return "Residual (" + TimeUnit[unit] + ")";
+            } else {
+                return "Residual";
+            }
+        }
+        case PlotName.SampleDrift:
+        case PlotName.Convergence:
+        case PlotName.QuantileQuantile: {
+            if (unit === 0 || unit) {
+                return "Mean Time Per Iteration (" + TimeUnit[unit] + ")";
+            } else {
+                return "Mean Time Per Iteration";
+            }
+        }
     }
 }
 
@@ -836,30 +1312,49 @@ const getAxisUnits = (unitsMap: UnitsMap, group: string, displayUnit: TimeUnit |
 export class CriterionMeanTimePerIterChart extends React.Component<CriterionChartProps> {
     render() {
         const group = this.props.group.toLowerCase();
-        let data = this.props.dataMap.get(group);
+        const relativeTo = this.props.relativeTo;
+        let data = relativeTo ? this.props.relativeDataMap?.get(group) : this.props.dataMap.get(group);
         if (!data) {
             return renderErrorDiv(group);
         }
+        if (relativeTo && !getRelativeInfo(this.props.relativeMap, group, relativeTo)) {
+            return renderRelativeErrorDiv(group, relativeTo);
+        }
         const unitsMap = this.props.unitsMap;
         let yAxisUnits = getAxisUnits(unitsMap, group, this.props.timeUnit);
+        let yLabelValue = getYLabelValue(yAxisUnits.display, PlotName.MeanTimePerIter, this.props.yLabel?.value);
+        if (relativeTo) {
+            // Ratios have no time unit to convert
+            yAxisUnits = { data: yAxisUnits.data, display: yAxisUnits.data };
+            yLabelValue = getRelativeLabelValue(relativeTo, this.props.yLabel?.value);
+        }
         let seriesYDataKeys = getYDataKeys(this.props.seriesInfoMap, this.props.series, group);
         return (
             <div className={this.props.className} style={this.props.style}>
+                {renderTitle(this.props.title, group, this.props.series, this.props.statsInfoMap)}
                 <CriterionLineChart
                     data={data}
                     series={seriesYDataKeys}
                     xLabelValue={getXLabelValue(null, this.props.iterMultiplier, PlotName.MeanTimePerIter, this.props.xLabel?.value)}
                     xLabelColor={this.props.xLabel?.color}
-                    yLabelValue={getYLabelValue(yAxisUnits.display, PlotName.MeanTimePerIter, this.props.yLabel?.value)}
+                    yLabelValue={yLabelValue}
                     yLabelColor={this.props.yLabel?.color}
                     timeUnits={yAxisUnits}
                     iterMultiplier={this.props.iterMultiplier}
                     xmin={this.props.xrange?.min}
                     xmax={this.props.xrange?.max}
                     legend={this.props.legend}>
+                    {relativeTo ? <ReferenceLine y={1} yAxisId="left" stroke="grey" strokeDasharray="3 3" /> : null}
                     {
                         this.props.series.map((series: Series) => (
-                            renderPlotLine(series, getSeriesLineDataKey(this.props.seriesInfoMap, series, group))
+                            [
+                                renderMeanBand(series,
+                                    getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'bmyIndex'),
+                                    getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'blyIndex'),
+                                    getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'buyIndex')),
+                                renderPlotLine(series, getSeriesLineDataKey(this.props.seriesInfoMap, series, group)),
+                                relativeTo ? null : renderBudgetLines(this.props.budgetMap, group, series, yAxisUnits.display, 'y'),
+                            ]
                         ))
                     }
                 </CriterionLineChart>
@@ -882,6 +1377,7 @@ export class CriterionLinearRegressionChart extends React.Component<CriterionCha
         let seriesYDataKeys = getYDataKeys(this.props.seriesInfoMap, this.props.series, group);
         return (
             <div className={this.props.className} style={this.props.style}>
+                {renderTitle(this.props.title, group, this.props.series, this.props.statsInfoMap)}
                 <CriterionLineChart
                     data={data}
                     series={seriesYDataKeys}
@@ -914,6 +1410,12 @@ export class CriterionProbabilityDensityChart extends React.Component<CriterionC
             return renderErrorDiv(group);
         }
         let seriesYDataKeys = getYDataKeys(this.props.seriesInfoMap, this.props.series, group, 'dyIndex');
+        let histogramYDataKeys = getYDataKeys(this.props.seriesInfoMap, this.props.series, group, 'hyIndex');
+        // Groups generated in histogram mode have bins instead of a KDE
+        const histogramMode = hasDataKeys(data, histogramYDataKeys);
+        if (histogramMode) {
+            seriesYDataKeys = histogramYDataKeys;
+        }
         const unitsMap = this.props.unitsMap;
         let xAxisUnits = getAxisUnits(unitsMap, group, this.props.timeUnit);
         let yRightLabelValue = this.props.yRightLabel?.value;
@@ -927,6 +1429,7 @@ export class CriterionProbabilityDensityChart extends React.Component<CriterionC
 
         return (
             <div className={this.props.className} style={this.props.style}>
+                {renderTitle(this.props.title, group, this.props.series, this.props.statsInfoMap)}
                 <CriterionLineChart
                     data={data}
                     series={seriesYDataKeys}
@@ -946,9 +1449,12 @@ export class CriterionProbabilityDensityChart extends React.Component<CriterionC
                     {
                         this.props.series.map((series: Series) => (
                             [
-                                renderArea(series, getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'dyIndex'), "left", 0),
+                                histogramMode ?
+                                    renderHistogram(series, getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'hyIndex')) :
+                                    renderArea(series, getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'dyIndex'), "left", 0),
                                 renderPlotLine(series, getSeriesLineDataKey(this.props.seriesInfoMap, series, group), "right", -1),
                                 renderReferenceLine(group, series.name, "mean", this.props.statsInfoMap, series.renderMean, series.mean?.stroke, series.mean?.strokeWidth),
+                                renderBudgetLines(this.props.budgetMap, group, series, xAxisUnits.display, 'x'),
                             ]
                         ))
                     }
@@ -958,4 +1464,830 @@ export class CriterionProbabilityDensityChart extends React.Component<CriterionC
         );
     }
 }
+
+// Draws the budgets of a series as threshold lines on the time axis
+const renderBudgetLines = (budgetMap: BudgetsInfoMap | undefined, group: string, series: Series, displayUnit: TimeUnit, axis: 'x' | 'y') => {
+    if (series.renderBudgets === false) {
+        return null;
+    }
+    const budgets = budgetMap?.get(group)?.get(series.name.toLowerCase());
+    if (!budgets) {
+        return null;
+    }
+    return budgets.map((budget: BudgetInfo) => {
+        const limit = convertTime(budget.limit, TimeUnit.ns, displayUnit);
+        const label = budget.statistic + ' ' + budget.operator + ' ' + Number(limit.toPrecision(3)) + ' ' + TimeUnit[displayUnit];
+        const key = 'budget-' + series.name + '-' + budget.statistic;
+        const stroke = series.budget?.stroke ? series.budget.stroke : (series.stroke ? series.stroke : 'red');
+        const strokeWidth = series.budget?.strokeWidth ? series.budget.strokeWidth : 1;
+        const strokeDasharray = series.budget?.strokeDasharray ? series.budget.strokeDasharray : '6 3';
+        return axis === 'x' ?
+            <ReferenceLine key={key} x={limit} yAxisId="left" stroke={stroke} strokeWidth={strokeWidth} strokeDasharray={strokeDasharray}>
+                <Label position="insideTopRight" value={label} />
+            </ReferenceLine> :
+            <ReferenceLine key={key} y={limit} yAxisId="left" stroke={stroke} strokeWidth={strokeWidth} strokeDasharray={strokeDasharray}>
+                <Label position="insideTopLeft" value={label} />
+            </ReferenceLine>;
+    });
+}
+
+const renderPercentileMarkers = (percentiles: number[] | undefined, marker: PercentileMarker | undefined) => {
+    if (!percentiles) {
+        return null;
+    }
+    return percentiles.map((p: number) => (
+        <ReferenceLine key={'p' + p} y={p} yAxisId="left"
+            stroke={marker?.stroke ? marker.stroke : 'grey'}
+            strokeWidth={marker?.strokeWidth ? marker.strokeWidth : 1}
+            strokeDasharray={marker?.strokeDasharray ? marker.strokeDasharray : '3 3'}>
+            <Label position="insideTopLeft" value={'p' + Math.round(p * 100)} />
+        </ReferenceLine>
+    ));
+}
+
+export class CriterionCdfChart extends React.Component<CriterionChartProps> {
+    render() {
+        const group = this.props.group.toLowerCase();
+        let data = this.props.dataMap.get(group);
+        if (!data) {
+            return renderErrorDiv(group);
+        }
+        let seriesYDataKeys = getYDataKeys(this.props.seriesInfoMap, this.props.series, group, 'cyIndex');
+        const unitsMap = this.props.unitsMap;
+        let xAxisUnits = getAxisUnits(unitsMap, group, this.props.timeUnit);
+
+        return (
+            <div className={this.props.className} style={this.props.style}>
+                {renderTitle(this.props.title, group, this.props.series, this.props.statsInfoMap)}
+                <CriterionLineChart
+                    data={data}
+                    series={seriesYDataKeys}
+                    xLabelValue={getXLabelValue(xAxisUnits.display, 1, PlotName.CumulativeDistribution, this.props.xLabel?.value)}
+                    xLabelColor={this.props.xLabel?.color}
+                    yLabelValue={getYLabelValue(null, PlotName.CumulativeDistribution, this.props.yLabel?.value)}
+                    yLabelColor={this.props.yLabel?.color}
+                    timeUnits={xAxisUnits}
+                    xmin={this.props.xrange?.min}
+                    xmax={this.props.xrange?.max}
+                    invertRescale={true}
+                    legend={this.props.legend}>
+                    {
+                        this.props.series.map((series: Series) => (
+                            renderPlotLine(series, getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'cyIndex'))
+                        ))
+                    }
+                    {renderPercentileMarkers(this.props.percentiles, this.props.percentileMarker)}
+                </CriterionLineChart>
+            </div>
+        );
+    }
+}
+
+export class CriterionSampleDriftChart extends React.Component<CriterionChartProps> {
+    render() {
+        const group = this.props.group.toLowerCase();
+        let data = this.props.dataMap.get(group);
+        if (!data) {
+            return renderErrorDiv(group);
+        }
+        const unitsMap = this.props.unitsMap;
+        let yAxisUnits = getAxisUnits(unitsMap, group, this.props.timeUnit);
+        let seriesYDataKeys = getYDataKeys(this.props.seriesInfoMap, this.props.series, group);
+        return (
+            <div className={this.props.className} style={this.props.style}>
+                {renderTitle(this.props.title, group, this.props.series, this.props.statsInfoMap)}
+                <CriterionLineChart
+                    data={data}
+                    series={seriesYDataKeys}
+                    xLabelValue={getXLabelValue(null, 1, PlotName.SampleDrift, this.props.xLabel?.value)}
+                    xLabelColor={this.props.xLabel?.color}
+                    yLabelValue={getYLabelValue(yAxisUnits.display, PlotName.SampleDrift, this.props.yLabel?.value)}
+                    yLabelColor={this.props.yLabel?.color}
+                    timeUnits={yAxisUnits}
+                    xmin={this.props.xrange?.min}
+                    xmax={this.props.xrange?.max}
+                    legend={this.props.legend}>
+                    {
+                        this.props.series.map((series: Series) => (
+                            [
+                                renderPlotLine(series, getSeriesLineDataKey(this.props.seriesInfoMap, series, group), "left", -1),
+                                renderTrendLine(series.name, series.trendLine, getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'ryIndex'), 'Running Mean'),
+                            ]
+                        ))
+                    }
+                </CriterionLineChart>
+            </div>
+        );
+    }
+}
+
+export class CriterionConvergenceChart extends React.Component<CriterionChartProps> {
+    render() {
+        const group = this.props.group.toLowerCase();
+        let data = this.props.dataMap.get(group);
+        if (!data) {
+            return renderErrorDiv(group);
+        }
+        const unitsMap = this.props.unitsMap;
+        let yAxisUnits = getAxisUnits(unitsMap, group, this.props.timeUnit);
+        let seriesYDataKeys = getYDataKeys(this.props.seriesInfoMap, this.props.series, group, 'lyIndex')
+            .concat(getYDataKeys(this.props.seriesInfoMap, this.props.series, group, 'uyIndex'));
+        return (
+            <div className={this.props.className} style={this.props.style}>
+                {renderTitle(this.props.title, group, this.props.series, this.props.statsInfoMap)}
+                <CriterionLineChart
+                    data={data}
+                    series={seriesYDataKeys}
+                    xLabelValue={getXLabelValue(null, 1, PlotName.Convergence, this.props.xLabel?.value)}
+                    xLabelColor={this.props.xLabel?.color}
+                    yLabelValue={getYLabelValue(yAxisUnits.display, PlotName.Convergence, this.props.yLabel?.value)}
+                    yLabelColor={this.props.yLabel?.color}
+                    timeUnits={yAxisUnits}
+                    xmin={this.props.xrange?.min}
+                    xmax={this.props.xrange?.max}
+                    legend={this.props.legend}>
+                    {
+                        this.props.series.map((series: Series) => (
+                            [
+                                renderConfidenceBand(series,
+                                    getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'lyIndex'),
+                                    getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'uyIndex')),
+                                renderPlotLine(series, getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'myIndex')),
+                            ]
+                        ))
+                    }
+                </CriterionLineChart>
+            </div>
+        );
+    }
+}
+
+export class CriterionQQChart extends React.Component<CriterionChartProps> {
+    render() {
+        const group = this.props.group.toLowerCase();
+        let data = this.props.dataMap.get(group);
+        if (!data) {
+            return renderErrorDiv(group);
+        }
+        const unitsMap = this.props.unitsMap;
+        let yAxisUnits = getAxisUnits(unitsMap, group, this.props.timeUnit);
+        let seriesYDataKeys = getYDataKeys(this.props.seriesInfoMap, this.props.series, group, 'qyIndex');
+        return (
+            <div className={this.props.className} style={this.props.style}>
+                {renderTitle(this.props.title, group, this.props.series, this.props.statsInfoMap)}
+                <CriterionLineChart
+                    data={data}
+                    series={seriesYDataKeys}
+                    xLabelValue={getXLabelValue(null, 1, PlotName.QuantileQuantile, this.props.xLabel?.value)}
+                    xLabelColor={this.props.xLabel?.color}
+                    yLabelValue={getYLabelValue(yAxisUnits.display, PlotName.QuantileQuantile, this.props.yLabel?.value)}
+                    yLabelColor={this.props.yLabel?.color}
+                    timeUnits={yAxisUnits}
+                    xmin={this.props.xrange?.min}
+                    xmax={this.props.xrange?.max}
+                    legend={this.props.legend}>
+                    {
+                        this.props.series.map((series: Series) => (
+                            [
+                                renderPlotLine(series, getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'qyIndex'), "left", -1),
+                                renderTrendLine(series.name, series.trendLine, getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'qryIndex'), 'Normal'),
+                            ]
+                        ))
+                    }
+                </CriterionLineChart>
+            </div>
+        );
+    }
+}
+
+export class CriterionResidualChart extends React.Component<CriterionChartProps> {
+    render() {
+        const group = this.props.group.toLowerCase();
+        let data = this.props.dataMap.get(group);
+        if (!data) {
+            return renderErrorDiv(group);
+        }
+        const unitsMap = this.props.unitsMap;
+        let yAxisUnits = getAxisUnits(unitsMap, group, this.props.timeUnit);
+        let seriesYDataKeys = getYDataKeys(this.props.seriesInfoMap, this.props.series, group, 'eyIndex');
+        return (
+            <div className={this.props.className} style={this.props.style}>
+                {renderTitle(this.props.title, group, this.props.series, this.props.statsInfoMap)}
+                <CriterionLineChart
+                    data={data}
+                    series={seriesYDataKeys}
+                    xLabelValue={getXLabelValue(null, this.props.iterMultiplier, PlotName.Residual, this.props.xLabel?.value)}
+                    xLabelColor={this.props.xLabel?.color}
+                    yLabelValue={getYLabelValue(yAxisUnits.display, PlotName.Residual, this.props.yLabel?.value)}
+                    yLabelColor={this.props.yLabel?.color}
+                    timeUnits={yAxisUnits}
+                    iterMultiplier={this.props.iterMultiplier}
+                    xmin={this.props.xrange?.min}
+                    xmax={this.props.xrange?.max}
+                    legend={this.props.legend}>
+                    {
+                        this.props.series.map((series: Series) => (
+                            renderPlotLine(series, getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'eyIndex'), "left", -1)
+                        ))
+                    }
+                    <ReferenceLine y={0} yAxisId="left" stroke="grey" strokeDasharray="3 3" />
+                </CriterionLineChart>
+            </div>
+        );
+    }
+}
+
+const CATEGORY_CHART_WIDTH: number = 500;
+const CATEGORY_CHART_HEIGHT: number = 300;
+const CATEGORY_CHART_MARGIN: ChartMargin = {
+    top: 10,
+    right: 20,
+    left: 80,
+    bottom: 50,
+};
+const CATEGORY_CHART_TICKS: number = 5;
+const CATEGORY_CHART_DEFAULT_FILL: string = '#8884d8';
+
+interface CategoryChartFrameProps {
+    categories: string[],
+    yMin: number,
+    yMax: number,
+    xLabelValue?: string,
+    xLabelColor?: string,
+    yLabelValue?: string,
+    yLabelColor?: string,
+    children: any,
+}
+
+const getCategoryPlotWidth = () => {
+    return CATEGORY_CHART_WIDTH - CATEGORY_CHART_MARGIN.left - CATEGORY_CHART_MARGIN.right;
+}
+
+const getCategoryPlotHeight = () => {
+    return CATEGORY_CHART_HEIGHT - CATEGORY_CHART_MARGIN.top - CATEGORY_CHART_MARGIN.bottom;
+}
+
+// Returns the center and the width of the band of a category on the x axis
+const getCategoryBand = (index: number, count: number) => {
+    let width = getCategoryPlotWidth() / count;
+    return {
+        center: CATEGORY_CHART_MARGIN.left + width * (index + 0.5),
+        width: width,
+    };
+}
+
+const getCategoryScaleY = (yMin: number, yMax: number) => {
+    let range = yMax === yMin ? 1 : yMax - yMin;
+    return (val: number) => CATEGORY_CHART_MARGIN.top + getCategoryPlotHeight() * (1 - (val - yMin) / range);
+}
+
+// Pads the range of the y axis so that the extremes are not drawn on the axes
+const padRange = (min: number, max: number) => {
+    let pad = (max - min) * 0.05;
+    if (pad === 0) {
+        pad = Math.abs(min) * 0.05 || 1;
+    }
+    return [min - pad, max + pad];
+}
+
+const flatten = (arrays: number[][]) => {
+    let flattened: number[] = [];
+    return flattened.concat(...arrays);
+}
+
+const formatTick = (val: number) => {
+    return Number(val.toPrecision(4)).toString();
+}
+
+const CategoryChartFrame = (props: CategoryChartFrameProps) => {
+    const scaleY = getCategoryScaleY(props.yMin, props.yMax);
+    const bottom = CATEGORY_CHART_HEIGHT - CATEGORY_CHART_MARGIN.bottom;
+    const right = CATEGORY_CHART_WIDTH - CATEGORY_CHART_MARGIN.right;
+    let ticks: number[] = [];
+    for (let i = 0; i < CATEGORY_CHART_TICKS; i++) {
+        ticks.push(props.yMin + (props.yMax - props.yMin) * i / (CATEGORY_CHART_TICKS - 1));
+    }
+
+    return (
+        <svg viewBox={'0 0 ' + CATEGORY_CHART_WIDTH + ' ' + CATEGORY_CHART_HEIGHT} width="100%" height="100%">
+            <line x1={CATEGORY_CHART_MARGIN.left} y1={CATEGORY_CHART_MARGIN.top} x2={CATEGORY_CHART_MARGIN.left} y2={bottom} stroke="#666" />
+            <line x1={CATEGORY_CHART_MARGIN.left} y1={bottom} x2={right} y2={bottom} stroke="#666" />
+            {
+                ticks.map((tick: number) => (
+                    <g key={'tick' + tick}>
+                        <line x1={CATEGORY_CHART_MARGIN.left - 5} y1={scaleY(tick)} x2={CATEGORY_CHART_MARGIN.left} y2={scaleY(tick)} stroke="#666" />
+                        <text x={CATEGORY_CHART_MARGIN.left - 8} y={scaleY(tick)} textAnchor="end" dominantBaseline="middle" fontSize={10} fill="#666">
+                            {formatTick(tick)}
+                        </text>
+                    </g>
+                ))
+            }
+            {
+                props.categories.map((category: string, i: number) => (
+                    <text key={category} x={getCategoryBand(i, props.categories.length).center} y={bottom + 15} textAnchor="middle" fontSize={10} fill="#666">
+                        {category}
+                    </text>
+                ))
+            }
+            {props.yLabelValue ?
+                <text transform={'translate(15,' + (CATEGORY_CHART_MARGIN.top + getCategoryPlotHeight() / 2) + ') rotate(-90)'} textAnchor="middle" fontSize={12} fill={props.yLabelColor}>
+                    {props.yLabelValue}
+                </text>
+                : null
+            }
+            {props.xLabelValue ?
+                <text x={CATEGORY_CHART_MARGIN.left + getCategoryPlotWidth() / 2} y={CATEGORY_CHART_HEIGHT - 10} textAnchor="middle" fontSize={12} fill={props.xLabelColor}>
+                    {props.xLabelValue}
+                </text>
+                : null
+            }
+            {props.children}
+        </svg>
+    );
+}
+
+const renderBox = (series: Series, boxPlot: BoxPlotInfo, index: number, count: number, scaleY: (val: number) => number, convert: (val: number) => number) => {
+    const band = getCategoryBand(index, count);
+    const boxWidth = band.width * 0.5;
+    const left = band.center - boxWidth / 2;
+    const stroke = series.stroke ? series.stroke : '#666';
+    const q1 = scaleY(convert(boxPlot.q1));
+    const q3 = scaleY(convert(boxPlot.q3));
+    const lowerWhisker = scaleY(convert(boxPlot.lowerWhisker));
+    const upperWhisker = scaleY(convert(boxPlot.upperWhisker));
+    return (
+        <g key={series.name}>
+            <title>{series.name}</title>
+            <line x1={band.center} y1={upperWhisker} x2={band.center} y2={q3} stroke={stroke} />
+            <line x1={band.center} y1={q1} x2={band.center} y2={lowerWhisker} stroke={stroke} />
+            <line x1={band.center - boxWidth / 4} y1={upperWhisker} x2={band.center + boxWidth / 4} y2={upperWhisker} stroke={stroke} />
+            <line x1={band.center - boxWidth / 4} y1={lowerWhisker} x2={band.center + boxWidth / 4} y2={lowerWhisker} stroke={stroke} />
+            <rect x={left} y={q3} width={boxWidth} height={Math.max(q1 - q3, 1)}
+                fill={series.areaFill ? series.areaFill : CATEGORY_CHART_DEFAULT_FILL} fillOpacity={0.6} stroke={stroke} />
+            <line x1={left} y1={scaleY(convert(boxPlot.median))} x2={left + boxWidth} y2={scaleY(convert(boxPlot.median))} stroke={stroke} strokeWidth={2} />
+            {
+                boxPlot.outliers.map((outlier: number, i: number) => (
+                    <circle key={i} cx={band.center} cy={scaleY(convert(outlier))} r={series.dot?.size ? series.dot.size : 2}
+                        fill={series.dot?.fill ? series.dot.fill : 'white'} stroke={series.dot?.stroke ? series.dot.stroke : stroke} />
+                ))
+            }
+        </g>
+    );
+}
+
+const renderViolin = (series: Series, violin: ViolinInfo, maxDensity: number, index: number, count: number, scaleY: (val: number) => number, convert: (val: number) => number) => {
+    const band = getCategoryBand(index, count);
+    const halfWidth = band.width * 0.45;
+    let right: string[] = [];
+    let left: string[] = [];
+    violin.x.forEach((x: number, i: number) => {
+        let offset = maxDensity > 0 ? halfWidth * violin.density[i] / maxDensity : 0;
+        let y = scaleY(convert(x));
+        right.push((band.center + offset) + ',' + y);
+        left.push((band.center - offset) + ',' + y);
+    });
+    // The KDE is mirrored around the center of the band
+    const points = right.concat(left.reverse()).join(' ');
+    return (
+        <g key={series.name}>
+            <title>{series.name}</title>
+            <polygon points={points} fill={series.areaFill ? series.areaFill : CATEGORY_CHART_DEFAULT_FILL} fillOpacity={0.6}
+                stroke={series.stroke} strokeWidth={series.strokeWidth ? series.strokeWidth : 1} />
+        </g>
+    );
+}
+
+const getSummaryValues = (summary: SummaryInfo, statistic: SummaryStatistic) => {
+    if (statistic === 'median') {
+        return [summary.median, summary.medianCiLower, summary.medianCiUpper];
+    }
+    return [summary.mean, summary.meanCiLower, summary.meanCiUpper];
+}
+
+const renderBar = (series: Series, values: number[], index: number, count: number, scaleY: (val: number) => number, convert: (val: number) => number, description?: string) => {
+    const band = getCategoryBand(index, count);
+    const barWidth = band.width * 0.6;
+    const capWidth = barWidth / 4;
+    const stroke = series.stroke ? series.stroke : '#666';
+    const [value, lower, upper] = values.map(convert);
+    const top = scaleY(value);
+    return (
+        <g key={series.name}>
+            <title>{description ? description : series.name + ': ' + formatTick(value) + ' [' + formatTick(lower) + ', ' + formatTick(upper) + ']'}</title>
+            <rect x={band.center - barWidth / 2} y={top} width={barWidth} height={Math.max(scaleY(0) - top, 1)}
+                fill={series.areaFill ? series.areaFill : CATEGORY_CHART_DEFAULT_FILL} fillOpacity={0.8} stroke={stroke} />
+            <line x1={band.center} y1={scaleY(upper)} x2={band.center} y2={scaleY(lower)} stroke={stroke} />
+            <line x1={band.center - capWidth} y1={scaleY(upper)} x2={band.center + capWidth} y2={scaleY(upper)} stroke={stroke} />
+            <line x1={band.center - capWidth} y1={scaleY(lower)} x2={band.center + capWidth} y2={scaleY(lower)} stroke={stroke} />
+        </g>
+    );
+}
+
+export class CriterionSummaryBarChart extends React.Component<CriterionCategoryChartProps> {
+    render() {
+        const group = this.props.group.toLowerCase();
+        let groupSummaryMap = this.props.summaryMap?.get(group);
+        if (!groupSummaryMap) {
+            return renderErrorDiv(group);
+        }
+        const statistic = this.props.statistic ? this.props.statistic : 'mean';
+        const relativeTo = this.props.relativeTo;
+        const groupRelativeMap = relativeTo ? getRelativeInfo(this.props.relativeMap, group, relativeTo) : null;
+        if (relativeTo && !groupRelativeMap) {
+            return renderRelativeErrorDiv(group, relativeTo);
+        }
+        const units = getAxisUnits(this.props.unitsMap, group, this.props.timeUnit);
+        const convert = relativeTo ? (val: number) => val : (val: number) => convertTime(val, units.data, units.display);
+        let bars: [Series, number[]][] = [];
+        this.props.series.forEach((series: Series) => {
+            if (groupRelativeMap) {
+                let relative = groupRelativeMap.get(series.name);
+                if (!relative) {
+                    console.error("Invalid Series Name: ", series.name);
+                    return;
+                }
+                if (relative.ratio === null) {
+                    console.warn("Series cannot be compared to the baseline: ", series.name);
+                    return;
+                }
+                bars.push([series, [relative.ratio, relative.ciLower ?? relative.ratio, relative.ciUpper ?? relative.ratio]]);
+                return;
+            }
+            let summary = groupSummaryMap?.get(series.name);
+            if (!summary) {
+                console.error("Invalid Series Name: ", series.name);
+                return;
+            }
+            bars.push([series, getSummaryValues(summary, statistic)]);
+        });
+        // Fastest to slowest
+        bars.sort((a, b) => a[1][0] - b[1][0]);
+        let values = flatten(bars.map(([_, values]) => values)).map(convert);
+        const yMax = padRange(0, Math.max(...values))[1];
+        const scaleY = getCategoryScaleY(0, yMax);
+        let yLabelValue = statistic === 'median' && !this.props.yLabel?.value ?
+            "Median Time Per Iteration (" + TimeUnit[units.display] + ")" :
+            getYLabelValue(units.display, PlotName.MeanTimePerIter, this.props.yLabel?.value);
+        if (relativeTo) {
+            yLabelValue = getRelativeLabelValue(relativeTo, this.props.yLabel?.value);
+        }
+
+        return (
+            <div className={this.props.className} style={this.props.style}>
+                {renderTitle(this.props.title, group, this.props.series, this.props.statsInfoMap)}
+                <CategoryChartFrame
+                    categories={bars.map(([series, _]) => series.name)}
+                    yMin={0}
+                    yMax={yMax}
+                    xLabelValue={this.props.xLabel?.value}
+                    xLabelColor={this.props.xLabel?.color}
+                    yLabelValue={yLabelValue}
+                    yLabelColor={this.props.yLabel?.color}>
+                    {
+                        bars.map(([series, values], i: number) => (
+                            renderBar(series, values, i, bars.length, scaleY, convert,
+                                relativeTo ? series.name + ': ' + formatRelative(values[0], relativeTo) : undefined)
+                        ))
+                    }
+                    {relativeTo ?
+                        <line x1={CATEGORY_CHART_MARGIN.left} y1={scaleY(1)} x2={CATEGORY_CHART_WIDTH - CATEGORY_CHART_MARGIN.right} y2={scaleY(1)}
+                            stroke="grey" strokeDasharray="3 3" />
+                        : null}
+                </CategoryChartFrame>
+            </div>
+        );
+    }
+}
+
+export class CriterionBoxPlotChart extends React.Component<CriterionCategoryChartProps> {
+    render() {
+        const group = this.props.group.toLowerCase();
+        let groupBoxPlotMap = this.props.boxPlotMap?.get(group);
+        if (!groupBoxPlotMap) {
+            return renderErrorDiv(group);
+        }
+        const units = getAxisUnits(this.props.unitsMap, group, this.props.timeUnit);
+        const convert = (val: number) => convertTime(val, units.data, units.display);
+        let boxPlots: [Series, BoxPlotInfo][] = [];
+        this.props.series.forEach((series: Series) => {
+            let boxPlot = groupBoxPlotMap?.get(series.name);
+            if (!boxPlot) {
+                console.error("Invalid Series Name: ", series.name);
+                return;
+            }
+            boxPlots.push([series, boxPlot]);
+        });
+        let values = flatten(boxPlots.map(([_, boxPlot]) => [boxPlot.lowerWhisker, boxPlot.upperWhisker, ...boxPlot.outliers])).map(convert);
+        let [yMin, yMax] = padRange(Math.min(...values), Math.max(...values));
+        const scaleY = getCategoryScaleY(yMin, yMax);
+
+        return (
+            <div className={this.props.className} style={this.props.style}>
+                {renderTitle(this.props.title, group, this.props.series, this.props.statsInfoMap)}
+                <CategoryChartFrame
+                    categories={boxPlots.map(([series, _]) => series.name)}
+                    yMin={yMin}
+                    yMax={yMax}
+                    xLabelValue={this.props.xLabel?.value}
+                    xLabelColor={this.props.xLabel?.color}
+                    yLabelValue={getYLabelValue(units.display, PlotName.MeanTimePerIter, this.props.yLabel?.value)}
+                    yLabelColor={this.props.yLabel?.color}>
+                    {
+                        boxPlots.map(([series, boxPlot], i: number) => (
+                            renderBox(series, boxPlot, i, boxPlots.length, scaleY, convert)
+                        ))
+                    }
+                </CategoryChartFrame>
+            </div>
+        );
+    }
+}
+
+export class CriterionViolinChart extends React.Component<CriterionCategoryChartProps> {
+    render() {
+        const group = this.props.group.toLowerCase();
+        let groupViolinMap = this.props.violinMap?.get(group);
+        if (!groupViolinMap) {
+            return renderErrorDiv(group);
+        }
+        const units = getAxisUnits(this.props.unitsMap, group, this.props.timeUnit);
+        const convert = (val: number) => convertTime(val, units.data, units.display);
+        let violins: [Series, ViolinInfo][] = [];
+        this.props.series.forEach((series: Series) => {
+            let violin = groupViolinMap?.get(series.name);
+            if (!violin) {
+                console.error("Invalid Series Name: ", series.name);
+                return;
+            }
+            violins.push([series, violin]);
+        });
+        let values = flatten(violins.map(([_, violin]) => violin.x)).map(convert);
+        let [yMin, yMax] = padRange(Math.min(...values), Math.max(...values));
+        const scaleY = getCategoryScaleY(yMin, yMax);
+        // All violins share the same density scale so that their widths are comparable
+        const maxDensity = Math.max(...flatten(violins.map(([_, violin]) => violin.density)));
+
+        return (
+            <div className={this.props.className} style={this.props.style}>
+                {renderTitle(this.props.title, group, this.props.series, this.props.statsInfoMap)}
+                <CategoryChartFrame
+                    categories={violins.map(([series, _]) => series.name)}
+                    yMin={yMin}
+                    yMax={yMax}
+                    xLabelValue={this.props.xLabel?.value}
+                    xLabelColor={this.props.xLabel?.color}
+                    yLabelValue={getYLabelValue(units.display, PlotName.MeanTimePerIter, this.props.yLabel?.value)}
+                    yLabelColor={this.props.yLabel?.color}>
+                    {
+                        violins.map(([series, violin], i: number) => (
+                            renderViolin(series, violin, maxDensity, i, violins.length, scaleY, convert)
+                        ))
+                    }
+                </CategoryChartFrame>
+            </div>
+        );
+    }
+}
+
+const getComplexityLabel = (complexityMap: ComplexityInfoMap | undefined, group: string, seriesName: string) => {
+    let complexityInfo = complexityMap?.get(group)?.get(seriesName);
+    if (!complexityInfo) {
+        return 'Fit';
+    }
+    return complexityInfo.model;
+}
+
+export class CriterionComplexityChart extends React.Component<CriterionChartProps> {
+    render() {
+        const group = this.props.group.toLowerCase();
+        let data = this.props.dataMap.get(group);
+        if (!data) {
+            return renderErrorDiv(group);
+        }
+        const unitsMap = this.props.unitsMap;
+        let yAxisUnits = getAxisUnits(unitsMap, group, this.props.timeUnit);
+        let seriesYDataKeys = getYDataKeys(this.props.seriesInfoMap, this.props.series, group);
+        return (
+            <div className={this.props.className} style={this.props.style}>
+                {renderTitle(this.props.title, group, this.props.series, this.props.statsInfoMap)}
+                <CriterionLineChart
+                    data={data}
+                    series={seriesYDataKeys}
+                    xLabelValue={getXLabelValue(null, 1, PlotName.Complexity, this.props.xLabel?.value)}
+                    xLabelColor={this.props.xLabel?.color}
+                    yLabelValue={getYLabelValue(yAxisUnits.display, PlotName.Complexity, this.props.yLabel?.value)}
+                    yLabelColor={this.props.yLabel?.color}
+                    timeUnits={yAxisUnits}
+                    xmin={this.props.xrange?.min}
+                    xmax={this.props.xrange?.max}
+                    legend={this.props.legend}>
+                    {
+                        this.props.series.map((series: Series) => (
+                            [renderPlotLine(series, getSeriesLineDataKey(this.props.seriesInfoMap, series, group), "left", -1),
+                            renderTrendLine(series.name, series.trendLine, getSeriesLineDataKey(this.props.seriesInfoMap, series, group, 'fyIndex'),
+                                getComplexityLabel(this.props.complexityMap, group, series.name.toLowerCase()))]
+                        ))
+                    }
+                </CriterionLineChart>
+            </div>
+        );
+    }
+}
+
+const getComparison = (comparisonMap: ComparisonMap, group: string, fnA: string, fnB: string): Comparison | null => {
+    let groupComparisonMap = comparisonMap.get(group);
+    if (!groupComparisonMap) {
+        console.error("Invalid Comparison Group: ", group);
+        return null;
+    }
+    let comparison = groupComparisonMap.get(fnA + COMPARISON_KEY_SEPARATOR + fnB);
+    if (comparison) {
+        return comparison;
+    }
+    // Only one ordering of each pair is generated so invert the other one
+    comparison = groupComparisonMap.get(fnB + COMPARISON_KEY_SEPARATOR + fnA);
+    if (!comparison) {
+        console.error("Invalid Comparison: ", fnA + COMPARISON_KEY_SEPARATOR + fnB);
+        return null;
+    }
+    const invert = (val: number | null) => val === null ? null : 1 / val;
+    return {
+        ratio: invert(comparison.ratio),
+        ciLower: invert(comparison.ciUpper),
+        ciUpper: invert(comparison.ciLower),
+        tPValue: comparison.tPValue,
+        uPValue: comparison.uPValue,
+        effectSize: comparison.effectSize === null ? null : -comparison.effectSize,
+    };
+}
+
+// The statistics of a comparison are null when they are not finite e.g. the
+// ratio to a zero mean
+const formatComparisonValue = (val: number | null, format: (val: number) => string) => {
+    return val === null ? 'n/a' : format(val);
+}
+
+export class CriterionComparison extends React.Component<CriterionComparisonTextProps> {
+    render() {
+        const group = this.props.group.toLowerCase();
+        let comparison = getComparison(this.props.comparisonMap, group, this.props.fnA.toLowerCase(), this.props.fnB.toLowerCase());
+        if (!comparison) {
+            return renderErrorDiv(group);
+        }
+        const precision = this.props.precision ?? 2;
+        let details = "Welch's t-test p = " + formatComparisonValue(comparison.tPValue, (p) => p.toPrecision(3))
+            + ", Mann-Whitney U p = " + formatComparisonValue(comparison.uPValue, (p) => p.toPrecision(3))
+            + ", Cohen's d = " + formatComparisonValue(comparison.effectSize, (d) => d.toFixed(precision));
+        if (comparison.ratio === null || comparison.ciLower === null || comparison.ciUpper === null) {
+            return (
+                <div className={this.props.className} style={this.props.style}>
+                    <span title={details}>
+                        {this.props.fnA} cannot be compared to {this.props.fnB}
+                    </span>
+                </div>
+            );
+        }
+        let factor = comparison.ratio;
+        let margin = (comparison.ciUpper - comparison.ciLower) / 2;
+        let verdict = "slower";
+        if (comparison.ratio < 1) {
+            factor = 1 / comparison.ratio;
+            margin = (1 / comparison.ciLower - 1 / comparison.ciUpper) / 2;
+            verdict = "faster";
+        }
+
+        return (
+            <div className={this.props.className} style={this.props.style}>
+                <span title={details}>
+                    {this.props.fnA} is {factor.toFixed(precision)}× {verdict} than {this.props.fnB} (±{margin.toFixed(precision)})
+                </span>
+            </div>
+        );
+    }
+}
+
+const HEATMAP_DEFAULT_COLORS: HeatmapColors = {
+    fast: '#1a9850',
+    neutral: '#ffffbf',
+    slow: '#d73027',
+};
+
+const parseHexColor = (color: string) => {
+    let hex = color.replace('#', '');
+    return [0, 2, 4].map((i: number) => parseInt(hex.substring(i, i + 2), 16));
+}
+
+// Interpolates between the fast, neutral and slow colors. t ranges from 0
+// (fast) to 1 (slow).
+const getHeatmapColor = (colors: HeatmapColors, t: number) => {
+    const fast = parseHexColor(colors.fast ? colors.fast : HEATMAP_DEFAULT_COLORS.fast!);
+    const neutral = parseHexColor(colors.neutral ? colors.neutral : HEATMAP_DEFAULT_COLORS.neutral!);
+    const slow = parseHexColor(colors.slow ? colors.slow : HEATMAP_DEFAULT_COLORS.slow!);
+    t = Math.min(Math.max(t, 0), 1);
+    const [from, to, f] = t < 0.5 ? [fast, neutral, t * 2] : [neutral, slow, (t - 0.5) * 2];
+    const rgb = from.map((c: number, i: number) => Math.round(c + (to[i] - c) * f));
+    return 'rgb(' + rgb.join(',') + ')';
+}
+
+// The position of a cell on the color scale. Means are scaled logarithmically
+// between the fastest and slowest function of their group. Relative times are
+// centered on the baseline with a 4x speedup or slowdown at the extremes.
+// Cells whose position is undefined (e.g. a zero mean) are centered.
+const getHeatmapScale = (mode: HeatmapMode, val: number, rowValues: number[]) => {
+    if (mode === 'relative') {
+        const t = 0.5 + Math.log2(val) / 4;
+        return isNaN(t) ? 0.5 : t;
+    }
+    const min = Math.log(Math.min(...rowValues));
+    const max = Math.log(Math.max(...rowValues));
+    if (max === min) {
+        return 0.5;
+    }
+    const t = (Math.log(val) - min) / (max - min);
+    return isNaN(t) ? 0.5 : t;
+}
+
+const formatHeatmapCell = (mode: HeatmapMode, val: number, timeUnit: TimeUnit, precision: number) => {
+    if (mode === 'relative') {
+        return val.toFixed(precision) + '×';
+    }
+    return convertTime(val, TimeUnit.ns, timeUnit).toFixed(precision) + ' ' + TimeUnit[timeUnit];
+}
+
+export class CriterionHeatmap extends React.Component<CriterionHeatmapChartProps> {
+    render() {
+        const matrix = this.props.matrix;
+        const mode = this.props.mode ? this.props.mode : 'mean';
+        const timeUnit = this.props.timeUnit !== undefined ? this.props.timeUnit : TimeUnit.ns;
+        const precision = this.props.precision ?? 2;
+        const colors = this.props.colors ? this.props.colors : HEATMAP_DEFAULT_COLORS;
+        const groups = this.props.groups ? this.props.groups.map((g: string) => g.toLowerCase()) : matrix.groups;
+        const functions = this.props.functions ? this.props.functions.map((f: string) => f.toLowerCase()) : matrix.functions;
+
+        let rows: [string, (number | null)[]][] = [];
+        groups.forEach((group: string) => {
+            const g = matrix.groups.indexOf(group);
+            if (g === -1) {
+                console.error("Invalid Group: ", group);
+                return;
+            }
+            let values = functions.map((fn: string) => {
+                const f = matrix.functions.indexOf(fn);
+                return f === -1 ? null : matrix.means[g][f];
+            });
+            if (mode === 'relative') {
+                const baseline = matrix.baselines[g];
+                const baselineIndex = baseline ? matrix.functions.indexOf(baseline) : -1;
+                if (baselineIndex === -1) {
+                    console.error("No baseline for Group: ", group);
+                    return;
+                }
+                const baselineMean = matrix.means[g][baselineIndex];
+                // A missing or zero baseline mean leaves every ratio undefined
+                values = values.map((val: number | null) => {
+                    const ratio = val === null || baselineMean === null ? NaN : val / baselineMean;
+                    return isFinite(ratio) ? ratio : null;
+                });
+            }
+            rows.push([group, values]);
+        });
+
+        return (
+            <div className={this.props.className} style={this.props.style}>
+                {this.props.title ? <div style={{ textAlign: 'center', fontWeight: 'bold' }}>{this.props.title}</div> : null}
+                <table style={{ borderCollapse: 'collapse', fontSize: '0.9em' }}>
+                    <thead>
+                        <tr>
+                            <th></th>
+                            {functions.map((fn: string) => <th key={fn} style={{ padding: '4px 8px' }}>{fn}</th>)}
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {
+                            rows.map(([group, values]) => {
+                                const rowValues = values.filter((val: number | null) => val !== null) as number[];
+                                return (
+                                    <tr key={group}>
+                                        <th style={{ padding: '4px 8px', textAlign: 'left' }}>{group}</th>
+                                        {
+                                            values.map((val: number | null, i: number) => (
+                                                <td key={functions[i]} style={{
+                                                    padding: '4px 8px',
+                                                    textAlign: 'right',
+                                                    backgroundColor: val === null ? undefined : getHeatmapColor(colors, getHeatmapScale(mode, val, rowValues)),
+                                                }}>
+                                                    {val === null ? 'n/a' : formatHeatmapCell(mode, val, timeUnit, precision)}
+                                                </td>
+                                            ))
+                                        }
+                                    </tr>
+                                );
+                            })
+                        }
+                    </tbody>
+                </table>
+            </div>
+        );
+    }
+}
 "###;
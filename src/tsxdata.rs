@@ -1,17 +1,32 @@
+use crate::infomap::{BoxPlotMap, Relative, RelativeMap, SummaryMap, ViolinMap};
+use crate::json::json_number;
+use crate::meanband::MeanBand;
+use crate::pdfmode::PdfMode;
 use crate::timeunit::TimeUnit;
 use crate::{infomap::StatsInfoMap, rawdata::CriterionDataPoint, stats::KdeDataSet};
 
 use crate::Density;
+use crate::FnName;
 use crate::GroupName;
 use crate::IterCount;
 use crate::KdeXVal;
 use crate::Measure;
+use crate::Param;
+use crate::SampleIndex;
 use crate::YIndex;
 
+use crate::stats::moving_average;
 use crate::stats::BvAnalysis;
+use crate::stats::Comparison;
+use crate::stats::ComplexityFit;
+use crate::stats::ConvergencePoint;
+use crate::stats::Histogram;
+use crate::stats::SuiteScore;
+use crate::stats::TrendLine;
 use crate::stats::UvAnalysis;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::error::Error;
 use std::fs::File;
 use std::io::Write;
@@ -39,7 +54,9 @@ pub trait TsxData {
     }
 }
 
-struct TsxYDataPoint(Measure, YIndex);
+const DRIFT_WINDOW: usize = 10;
+
+struct TsxYDataPoint(Measure, YIndex, SampleIndex);
 
 pub struct TsxDataSet {
     points: BTreeMap<IterCount, Vec<TsxYDataPoint>>,
@@ -47,6 +64,7 @@ pub struct TsxDataSet {
     bv_analysis: BTreeMap<YIndex, BvAnalysis>,
     time_unit: Option<TimeUnit>,
     trendlines: bool,
+    mean_band: Option<MeanBand>,
 }
 
 impl TsxDataSet {
@@ -56,18 +74,135 @@ impl TsxDataSet {
             uv_analysis: BTreeMap::<YIndex, UvAnalysis>::new(),
             bv_analysis: BTreeMap::<YIndex, BvAnalysis>::new(),
             time_unit: None,
-            trendlines: trendlines,
+            trendlines,
+            mean_band: None,
+        }
+    }
+
+    /// Adds the mean and the bounds of the band around it to every point
+    pub fn set_mean_band(&mut self, mean_band: Option<MeanBand>) {
+        self.mean_band = mean_band;
+    }
+
+    /// The mean, lower and upper bound of the band of every function with at
+    /// least two samples. Empty unless a band was set.
+    pub fn mean_bands(&self) -> BTreeMap<YIndex, (Measure, Measure, Measure)> {
+        let mut bands = BTreeMap::<YIndex, (Measure, Measure, Measure)>::new();
+        let mean_band = match self.mean_band {
+            Some(mean_band) => mean_band,
+            None => return bands,
+        };
+        for (y_index, uva) in &self.uv_analysis {
+            if uva.n() < 2 {
+                continue;
+            }
+            let (lower, upper) = match mean_band {
+                MeanBand::ConfidenceInterval => uva.mean_ci(),
+                MeanBand::StdDev(k) => (
+                    uva.mean() - k * uva.std_dev(),
+                    uva.mean() + k * uva.std_dev(),
+                ),
+            };
+            bands.insert(*y_index, (uva.mean(), lower, upper));
+        }
+        bands
+    }
+
+    pub fn time_unit(&self) -> Option<TimeUnit> {
+        self.time_unit
+    }
+
+    /// The index and name of every function in the dataset
+    pub fn functions(&self) -> Vec<(YIndex, &FnName)> {
+        self.uv_analysis
+            .iter()
+            .map(|(y_index, uva)| (*y_index, uva.fn_name()))
+            .collect()
+    }
+
+    pub fn uv_analysis(&self, y_index: YIndex) -> Option<&UvAnalysis> {
+        self.uv_analysis.get(&y_index)
+    }
+
+    /// The iteration count and measurement of every sample of a function in
+    /// iteration count order
+    pub fn points(&self, y_index: YIndex) -> Vec<(IterCount, Measure)> {
+        let mut points = Vec::<(IterCount, Measure)>::new();
+        for (iter_count, y_values) in &self.points {
+            for y_value in y_values {
+                if y_value.1 == y_index {
+                    points.push((*iter_count, y_value.0));
+                }
+            }
         }
+        points
+    }
+
+    /// The trendline of a function. None unless the dataset has trendlines.
+    pub fn trendline(&self, y_index: YIndex) -> Option<TrendLine> {
+        self.bv_analysis.get(&y_index).map(|bva| bva.trendline())
     }
 
     pub fn get_uv_stats(&self, group: &str, si_map: &mut StatsInfoMap) {
-        for (_, uva) in &self.uv_analysis {
+        for uva in self.uv_analysis.values() {
             let s_info = uva.stats_info();
             let fn_name = uva.fn_name();
             si_map.push(group, fn_name, s_info);
         }
     }
 
+    pub fn get_box_plots(&self, group: &str, bp_map: &mut BoxPlotMap) {
+        for uva in self.uv_analysis.values() {
+            bp_map.push(group, uva.fn_name(), uva.box_plot());
+        }
+    }
+
+    pub fn get_summaries(&self, group: &str, s_map: &mut SummaryMap) {
+        for uva in self.uv_analysis.values() {
+            s_map.push(group, uva.fn_name(), uva.summary());
+        }
+    }
+
+    /// The first function in `relative_to` that belongs to this dataset
+    fn baseline(&self, relative_to: &[FnName]) -> Option<(YIndex, &UvAnalysis)> {
+        relative_to.iter().find_map(|baseline| {
+            self.uv_analysis
+                .iter()
+                .find(|(_, uva)| uva.fn_name().eq_ignore_ascii_case(baseline))
+                .map(|(y_index, uva)| (*y_index, uva))
+        })
+    }
+
+    pub fn get_relatives(&self, group: &str, relative_to: &[FnName], r_map: &mut RelativeMap) {
+        if let Some((_, baseline)) = self.baseline(relative_to) {
+            for uva in self.uv_analysis.values() {
+                if let Some(comparison) = Comparison::new(uva, baseline) {
+                    r_map.push(group, uva.fn_name(), Relative::new(comparison));
+                }
+            }
+        }
+    }
+
+    /// The per iteration times of every function as a multiple of the mean
+    /// time of the baseline function. Empty if the group has no baseline.
+    pub fn to_relative_data(&self, relative_to: &[FnName]) -> RelativeDataSet {
+        let mut relative_data = RelativeDataSet::new();
+        if let Some((_, baseline)) = self.baseline(relative_to) {
+            for (iter_count, y_values) in &self.points {
+                for y_value in y_values {
+                    relative_data.push(*iter_count, y_value.1, y_value.0 / baseline.mean());
+                }
+            }
+        }
+        relative_data
+    }
+
+    pub fn get_violins(&self, group: &str, v_map: &mut ViolinMap) {
+        for uva in self.uv_analysis.values() {
+            v_map.push(group, uva.fn_name(), uva.violin(100));
+        }
+    }
+
     pub fn insert(&mut self, fn_name: &str, datapoint: &CriterionDataPoint, y_index: YIndex) {
         //TODO: Convert the time to the right unit rather than panicing
         match self.time_unit {
@@ -85,10 +220,10 @@ impl TsxDataSet {
         let time_val: f64 = datapoint.measurement(); //y_val
         let iter_val = datapoint.iter_count(); //x_val
         if let Some(points) = self.points.get_mut(&iter_val) {
-            points.push(TsxYDataPoint(time_val, y_index));
+            points.push(TsxYDataPoint(time_val, y_index, datapoint.sample_index()));
         } else {
             let mut points = Vec::<TsxYDataPoint>::with_capacity(crate::SAMPLE_SIZE);
-            points.push(TsxYDataPoint(time_val, y_index));
+            points.push(TsxYDataPoint(time_val, y_index, datapoint.sample_index()));
             self.points.insert(iter_val, points);
         }
 
@@ -111,7 +246,7 @@ impl TsxDataSet {
         }
     }
 
-    pub fn to_pdf_data(&self) -> PdfDataSet {
+    pub fn to_pdf_data(&self, pdf_mode: PdfMode) -> PdfDataSet {
         let mut pdf_data = PdfDataSet::new();
         for (iter_count, y_values) in &self.points {
             for y_value in y_values {
@@ -120,22 +255,108 @@ impl TsxDataSet {
         }
 
         for (y_index, uva) in &self.uv_analysis {
-            let kde_data = &uva.kdensity(500);
-            pdf_data.add_kde_data(kde_data, *y_index);
+            match pdf_mode {
+                PdfMode::Kde => {
+                    let kde_data = &uva.kdensity(500);
+                    pdf_data.add_kde_data(kde_data, *y_index);
+                }
+                PdfMode::Histogram(bin_rule) => {
+                    let histogram = &uva.histogram(bin_rule);
+                    pdf_data.add_histogram_data(histogram, *y_index);
+                }
+            }
         }
         pdf_data.sort();
         pdf_data
     }
+
+    /// The samples of each function in the order in which they were taken
+    /// along with a moving average over the last DRIFT_WINDOW samples.
+    pub fn to_drift_data(&self) -> DriftDataSet {
+        let mut samples = BTreeMap::<YIndex, Vec<(SampleIndex, Measure)>>::new();
+        for y_values in self.points.values() {
+            for y_value in y_values {
+                samples
+                    .entry(y_value.1)
+                    .or_default()
+                    .push((y_value.2, y_value.0));
+            }
+        }
+
+        let mut drift_data = DriftDataSet::new();
+        for (y_index, samples) in samples.iter_mut() {
+            samples.sort_by_key(|(sample_index, _)| *sample_index);
+            let values: Vec<Measure> = samples.iter().map(|(_, val)| *val).collect();
+            let averages = moving_average(&values, DRIFT_WINDOW);
+            for ((sample_index, val), average) in samples.iter().zip(averages) {
+                drift_data.push(*sample_index, *y_index, *val, average);
+            }
+        }
+        drift_data
+    }
+
+    /// The cumulative mean time per iteration of each function, and its
+    /// confidence interval, as the number of samples grows.
+    pub fn to_convergence_data(&self) -> ConvergenceDataSet {
+        let mut convergence_data = ConvergenceDataSet::new();
+        for (y_index, uva) in &self.uv_analysis {
+            for point in uva.convergence() {
+                convergence_data.push(*y_index, point);
+            }
+        }
+        convergence_data
+    }
+
+    pub fn to_cdf_data(&self) -> CdfDataSet {
+        let mut cdf_data = CdfDataSet::new();
+        for (y_index, uva) in &self.uv_analysis {
+            cdf_data.add_ecdf(&uva.ecdf(), *y_index);
+        }
+        cdf_data.sort();
+        cdf_data
+    }
+
+    /// The residual of every sample against its function's trendline. Empty
+    /// unless the dataset has trendlines.
+    pub fn to_residual_data(&self) -> ResidualDataSet {
+        let mut residual_data = ResidualDataSet::new();
+        for (iter_count, y_values) in &self.points {
+            for y_value in y_values {
+                if let Some(bva) = self.bv_analysis.get(&y_value.1) {
+                    let residual = bva.residual(*iter_count as f64, y_value.0);
+                    residual_data.push(*iter_count, y_value.1, residual);
+                }
+            }
+        }
+        residual_data
+    }
+
+    pub fn to_qq_data(&self) -> QqDataSet {
+        let mut qq_data = QqDataSet::new();
+        for (y_index, uva) in &self.uv_analysis {
+            qq_data.add_qq(&uva.qq(), uva.mean(), uva.std_dev(), *y_index);
+        }
+        qq_data.sort();
+        qq_data
+    }
 }
 
 impl ToTsxFile for TsxDataSet {
     fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
         let mut i: u16 = 0;
+        let mean_bands = self.mean_bands();
         for (iter_count, y_values) in &self.points {
             write!(tsxfile, "        {{i:{},x:{},", i, (*iter_count as f64))?;
             for y_value in y_values {
                 write!(tsxfile, "y{}:{},", y_value.1, y_value.0)?;
             }
+            for (y_index, (mean, lower, upper)) in &mean_bands {
+                write!(
+                    tsxfile,
+                    "bm{}:{},bl{}:{},bu{}:{},",
+                    y_index, mean, y_index, lower, y_index, upper
+                )?;
+            }
             writeln!(tsxfile, "}},")?;
             i += 1;
         }
@@ -174,6 +395,9 @@ pub struct TsxDataMap {
     dataset_name: String,
     trendlines: bool,
     pdfdata: bool,
+    pdf_modes: BTreeMap<GroupName, PdfMode>,
+    mean_band: Option<MeanBand>,
+    relative_to: Vec<FnName>,
 }
 
 impl TsxDataMap {
@@ -181,8 +405,11 @@ impl TsxDataMap {
         TsxDataMap {
             data: BTreeMap::<GroupName, TsxDataSet>::new(),
             dataset_name: dataset_name.to_ascii_uppercase(),
-            trendlines: trendlines,
-            pdfdata: pdfdata,
+            trendlines,
+            pdfdata,
+            pdf_modes: BTreeMap::<GroupName, PdfMode>::new(),
+            mean_band: None,
+            relative_to: Vec::<FnName>::new(),
         }
     }
 
@@ -200,6 +427,7 @@ impl TsxDataMap {
             None => {
                 //Doesn't exist so add it
                 let mut dataset = TsxDataSet::new(self.trendlines);
+                dataset.set_mean_band(self.mean_band);
                 dataset.insert(fn_name, datapoint, y_index);
                 self.data.insert(group.to_ascii_lowercase(), dataset);
             }
@@ -210,6 +438,34 @@ impl TsxDataMap {
         self.data.get(group)
     }
 
+    /// The PDF data of a group in the PDF mode of the group
+    pub fn pdf_data(&self, group: &str) -> Option<PdfDataSet> {
+        self.data
+            .get(group)
+            .map(|dataset| dataset.to_pdf_data(self.pdf_mode(group)))
+    }
+
+    pub fn set_pdf_mode(&mut self, group: &str, pdf_mode: PdfMode) {
+        self.pdf_modes.insert(group.to_ascii_lowercase(), pdf_mode);
+    }
+
+    fn pdf_mode(&self, group: &str) -> PdfMode {
+        self.pdf_modes.get(group).copied().unwrap_or_default()
+    }
+
+    /// Sets the candidate baseline functions. The baseline of a group is the
+    /// first of them that is in the group.
+    pub fn set_relative_to(&mut self, relative_to: &[FnName]) {
+        self.relative_to = relative_to.to_vec();
+    }
+
+    pub fn set_mean_band(&mut self, mean_band: Option<MeanBand>) {
+        self.mean_band = mean_band;
+        for dataset in self.data.values_mut() {
+            dataset.set_mean_band(mean_band);
+        }
+    }
+
     fn write_units_tsx_to_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
         writeln!(
             tsxfile,
@@ -217,6 +473,10 @@ impl TsxDataMap {
             self.dataset_name
         )?;
         let mut stats_info_map = StatsInfoMap::new();
+        let mut box_plot_map = BoxPlotMap::new();
+        let mut violin_map = ViolinMap::new();
+        let mut summary_map = SummaryMap::new();
+        let mut relative_map = RelativeMap::new();
 
         let mut time_unit: TimeUnit;
         for (group, tsx_data) in &self.data {
@@ -227,18 +487,21 @@ impl TsxDataMap {
 
             if self.pdfdata {
                 tsx_data.get_uv_stats(group, &mut stats_info_map);
+                tsx_data.get_box_plots(group, &mut box_plot_map);
+                tsx_data.get_violins(group, &mut violin_map);
+                tsx_data.get_summaries(group, &mut summary_map);
+                tsx_data.get_relatives(group, &self.relative_to, &mut relative_map);
             }
 
-            writeln!(
-                tsxfile,
-                "    [\"{}\", TimeUnit.{}],",
-                group,
-                time_unit.to_string()
-            )?;
+            writeln!(tsxfile, "    [\"{}\", TimeUnit.{}],", group, time_unit)?;
         }
         writeln!(tsxfile, "]);")?;
         if self.pdfdata {
             stats_info_map.to_tsx_file(tsxfile)?;
+            box_plot_map.to_tsx_file(tsxfile)?;
+            violin_map.to_tsx_file(tsxfile)?;
+            summary_map.to_tsx_file(tsxfile)?;
+            relative_map.to_tsx_file(tsxfile)?;
         }
         Ok(())
     }
@@ -259,16 +522,86 @@ impl ToTsxFile for TsxDataMap {
         }
         writeln!(tsxfile, "]);")?;
 
+        if self.trendlines {
+            writeln!(
+                tsxfile,
+                "const RESIDUAL_DATA_MAP: Map<string, DataPoint[]> = new Map<string, DataPoint[]>([",
+            )?;
+            for (group, tsx_data) in &self.data {
+                writeln!(tsxfile, "    [\"{}\", [", group)?;
+                tsx_data.to_residual_data().to_tsx_file(tsxfile)?;
+                writeln!(tsxfile, "    ]],")?;
+            }
+            writeln!(tsxfile, "]);")?;
+        }
+
         if self.pdfdata {
+            writeln!(
+                tsxfile,
+                "const RELATIVE_DATA_MAP: Map<string, DataPoint[]> = new Map<string, DataPoint[]>([",
+            )?;
             for (group, tsx_data) in &self.data {
-                writeln!(
-                    tsxfile,
-                    "const PDF_DATA_MAP: Map<string, DataPoint[]> = new Map<string, DataPoint[]>([",
-                )?;
                 writeln!(tsxfile, "    [\"{}\", [", group)?;
-                let pdf_data = tsx_data.to_pdf_data();
+                tsx_data
+                    .to_relative_data(&self.relative_to)
+                    .to_tsx_file(tsxfile)?;
+                writeln!(tsxfile, "    ]],")?;
+            }
+            writeln!(tsxfile, "]);")?;
+
+            writeln!(
+                tsxfile,
+                "const PDF_DATA_MAP: Map<string, DataPoint[]> = new Map<string, DataPoint[]>([",
+            )?;
+            for (group, tsx_data) in &self.data {
+                writeln!(tsxfile, "    [\"{}\", [", group)?;
+                let pdf_data = tsx_data.to_pdf_data(self.pdf_mode(group));
                 pdf_data.to_tsx_file(tsxfile)?;
-                writeln!(tsxfile, "    ]]")?;
+                writeln!(tsxfile, "    ]],")?;
+            }
+            writeln!(tsxfile, "]);")?;
+
+            writeln!(
+                tsxfile,
+                "const CDF_DATA_MAP: Map<string, DataPoint[]> = new Map<string, DataPoint[]>([",
+            )?;
+            for (group, tsx_data) in &self.data {
+                writeln!(tsxfile, "    [\"{}\", [", group)?;
+                tsx_data.to_cdf_data().to_tsx_file(tsxfile)?;
+                writeln!(tsxfile, "    ]],")?;
+            }
+            writeln!(tsxfile, "]);")?;
+
+            writeln!(
+                tsxfile,
+                "const DRIFT_DATA_MAP: Map<string, DataPoint[]> = new Map<string, DataPoint[]>([",
+            )?;
+            for (group, tsx_data) in &self.data {
+                writeln!(tsxfile, "    [\"{}\", [", group)?;
+                tsx_data.to_drift_data().to_tsx_file(tsxfile)?;
+                writeln!(tsxfile, "    ]],")?;
+            }
+            writeln!(tsxfile, "]);")?;
+
+            writeln!(
+                tsxfile,
+                "const CONVERGENCE_DATA_MAP: Map<string, DataPoint[]> = new Map<string, DataPoint[]>([",
+            )?;
+            for (group, tsx_data) in &self.data {
+                writeln!(tsxfile, "    [\"{}\", [", group)?;
+                tsx_data.to_convergence_data().to_tsx_file(tsxfile)?;
+                writeln!(tsxfile, "    ]],")?;
+            }
+            writeln!(tsxfile, "]);")?;
+
+            writeln!(
+                tsxfile,
+                "const QQ_DATA_MAP: Map<string, DataPoint[]> = new Map<string, DataPoint[]>([",
+            )?;
+            for (group, tsx_data) in &self.data {
+                writeln!(tsxfile, "    [\"{}\", [", group)?;
+                tsx_data.to_qq_data().to_tsx_file(tsxfile)?;
+                writeln!(tsxfile, "    ]],")?;
             }
             writeln!(tsxfile, "]);")?;
         }
@@ -280,12 +613,32 @@ pub struct MeanTimeData {
     data: TsxDataMap,
 }
 
+impl Default for MeanTimeData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl MeanTimeData {
     pub fn new() -> MeanTimeData {
         MeanTimeData {
             data: TsxDataMap::new("MEAN_TIME_DATA", false, true),
         }
     }
+
+    pub fn set_pdf_mode(&mut self, group: &str, pdf_mode: PdfMode) {
+        self.data.set_pdf_mode(group, pdf_mode);
+    }
+
+    /// Adds the band around the mean to every point. Without a band the
+    /// data has no band keys.
+    pub fn set_mean_band(&mut self, mean_band: MeanBand) {
+        self.data.set_mean_band(Some(mean_band));
+    }
+
+    pub fn set_relative_to(&mut self, relative_to: &[FnName]) {
+        self.data.set_relative_to(relative_to);
+    }
 }
 
 impl ToTsxFile for MeanTimeData {
@@ -308,6 +661,12 @@ pub struct TotalTimeData {
     data: TsxDataMap,
 }
 
+impl Default for TotalTimeData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TotalTimeData {
     pub fn new() -> TotalTimeData {
         TotalTimeData {
@@ -332,9 +691,21 @@ impl TsxData for TotalTimeData {
     }
 }
 
+/// The values at the x value of a PDF data point
+#[derive(Debug, Copy, Clone)]
+pub enum PdfValue {
+    /// A sample and its iteration count
+    Measurement(IterCount),
+    /// A point of the kernel density estimate
+    Kde(Density),
+    /// The lower edge of a histogram bin with its density and count
+    Histogram(Density, u32),
+}
+
 struct PdfDataPoint {
     x_val: Measure,
-    y_values: String,
+    y_index: YIndex,
+    value: PdfValue,
 }
 
 impl PdfDataPoint {
@@ -345,14 +716,29 @@ impl PdfDataPoint {
     ) -> PdfDataPoint {
         PdfDataPoint {
             x_val,
-            y_values: format!("y{}:{}", y_index, iter_count),
+            y_index,
+            value: PdfValue::Measurement(iter_count),
         }
     }
 
     pub fn from_kde_data(x_val: KdeXVal, y_index: YIndex, density: Density) -> PdfDataPoint {
         PdfDataPoint {
             x_val,
-            y_values: format!("d{}:{}", y_index, density),
+            y_index,
+            value: PdfValue::Kde(density),
+        }
+    }
+
+    pub fn from_histogram_bin(
+        x_val: Measure,
+        y_index: YIndex,
+        density: Density,
+        count: u32,
+    ) -> PdfDataPoint {
+        PdfDataPoint {
+            x_val,
+            y_index,
+            value: PdfValue::Histogram(density, count),
         }
     }
 
@@ -360,8 +746,14 @@ impl PdfDataPoint {
         self.x_val
     }
 
-    pub fn y_values(&self) -> &str {
-        self.y_values.as_str()
+    pub fn y_values(&self) -> String {
+        match self.value {
+            PdfValue::Measurement(iter_count) => format!("y{}:{}", self.y_index, iter_count),
+            PdfValue::Kde(density) => format!("d{}:{}", self.y_index, density),
+            PdfValue::Histogram(density, count) => {
+                format!("h{}:{},hc{}:{}", self.y_index, density, self.y_index, count)
+            }
+        }
     }
 }
 
@@ -369,6 +761,12 @@ pub struct PdfDataSet {
     data: Vec<PdfDataPoint>,
 }
 
+impl Default for PdfDataSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PdfDataSet {
     pub fn new() -> PdfDataSet {
         PdfDataSet {
@@ -399,16 +797,44 @@ impl PdfDataSet {
         }
     }
 
+    /// Adds a point at the lower edge of each bin and a closing point at the
+    /// upper edge of the last bin so that the bins can be drawn as steps.
+    pub fn add_histogram_data(&mut self, histogram: &Histogram, y_index: YIndex) {
+        let edges = histogram.edges();
+        for (bin, count) in histogram.counts().iter().enumerate() {
+            self.data.push(PdfDataPoint::from_histogram_bin(
+                edges[bin],
+                y_index,
+                histogram.density(bin),
+                *count,
+            ));
+        }
+        if let Some(last_edge) = edges.last() {
+            self.data.push(PdfDataPoint::from_histogram_bin(
+                *last_edge, y_index, 0.0, 0,
+            ));
+        }
+    }
+
     pub fn sort(&mut self) {
         self.data
             .sort_by(|a, b| a.x_val.partial_cmp(&b.x_val).unwrap());
     }
+
+    /// The x value and the value of every point of a function in x order if
+    /// the dataset is sorted
+    pub fn values(&self, y_index: YIndex) -> Vec<(Measure, PdfValue)> {
+        self.data
+            .iter()
+            .filter(|point| point.y_index == y_index)
+            .map(|point| (point.x_val, point.value))
+            .collect()
+    }
 }
 
 impl ToTsxFile for PdfDataSet {
     fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
-        let mut i = 0;
-        for v in &self.data {
+        for (i, v) in self.data.iter().enumerate() {
             writeln!(
                 tsxfile,
                 "        {{i:{},x:{},{}}},",
@@ -416,8 +842,522 @@ impl ToTsxFile for PdfDataSet {
                 v.x_val(),
                 v.y_values()
             )?;
-            i += 1;
         }
         Ok(())
     }
 }
+
+/// The per iteration time of every sample keyed by the sample index
+pub struct DriftDataSet {
+    data: BTreeMap<SampleIndex, Vec<(YIndex, Measure, Measure)>>,
+}
+
+impl Default for DriftDataSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DriftDataSet {
+    pub fn new() -> DriftDataSet {
+        DriftDataSet {
+            data: BTreeMap::<SampleIndex, Vec<(YIndex, Measure, Measure)>>::new(),
+        }
+    }
+
+    pub fn push(
+        &mut self,
+        sample_index: SampleIndex,
+        y_index: YIndex,
+        measurement: Measure,
+        moving_average: Measure,
+    ) {
+        self.data
+            .entry(sample_index)
+            .or_default()
+            .push((y_index, measurement, moving_average));
+    }
+
+    /// The measurement and moving average of every function at a sample index
+    pub fn get(&self, sample_index: SampleIndex) -> Option<&Vec<(YIndex, Measure, Measure)>> {
+        self.data.get(&sample_index)
+    }
+}
+
+impl ToTsxFile for DriftDataSet {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        for (i, (sample_index, y_values)) in self.data.iter().enumerate() {
+            write!(tsxfile, "        {{i:{},x:{},", i, sample_index)?;
+            for (y_index, measurement, moving_average) in y_values {
+                write!(
+                    tsxfile,
+                    "y{}:{},rm{}:{},",
+                    y_index, measurement, y_index, moving_average
+                )?;
+            }
+            writeln!(tsxfile, "}},")?;
+        }
+        Ok(())
+    }
+}
+
+/// The cumulative mean and confidence interval of every function keyed by
+/// the number of samples seen
+pub struct ConvergenceDataSet {
+    data: BTreeMap<u32, Vec<(YIndex, ConvergencePoint)>>,
+}
+
+impl Default for ConvergenceDataSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConvergenceDataSet {
+    pub fn new() -> ConvergenceDataSet {
+        ConvergenceDataSet {
+            data: BTreeMap::<u32, Vec<(YIndex, ConvergencePoint)>>::new(),
+        }
+    }
+
+    pub fn push(&mut self, y_index: YIndex, point: ConvergencePoint) {
+        self.data
+            .entry(point.n())
+            .or_default()
+            .push((y_index, point));
+    }
+}
+
+impl ToTsxFile for ConvergenceDataSet {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        for (i, (n, y_values)) in self.data.iter().enumerate() {
+            write!(tsxfile, "        {{i:{},x:{},", i, n)?;
+            for (y_index, point) in y_values {
+                write!(
+                    tsxfile,
+                    "cm{}:{},cl{}:{},cu{}:{},",
+                    y_index,
+                    point.mean(),
+                    y_index,
+                    point.ci_lower(),
+                    y_index,
+                    point.ci_upper()
+                )?;
+            }
+            writeln!(tsxfile, "}},")?;
+        }
+        Ok(())
+    }
+}
+
+struct CdfDataPoint {
+    x_val: Measure,
+    y_index: YIndex,
+    probability: f64,
+}
+
+/// The empirical cumulative distribution of the mean time per iteration of
+/// every function in a group.
+pub struct CdfDataSet {
+    data: Vec<CdfDataPoint>,
+}
+
+impl Default for CdfDataSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CdfDataSet {
+    pub fn new() -> CdfDataSet {
+        CdfDataSet {
+            data: Vec::<CdfDataPoint>::new(),
+        }
+    }
+
+    pub fn add_ecdf(&mut self, ecdf: &[(Measure, f64)], y_index: YIndex) {
+        for (x_val, probability) in ecdf {
+            self.data.push(CdfDataPoint {
+                x_val: *x_val,
+                y_index,
+                probability: *probability,
+            });
+        }
+    }
+
+    pub fn sort(&mut self) {
+        self.data
+            .sort_by(|a, b| a.x_val.partial_cmp(&b.x_val).unwrap());
+    }
+}
+
+impl ToTsxFile for CdfDataSet {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        for (i, v) in self.data.iter().enumerate() {
+            writeln!(
+                tsxfile,
+                "        {{i:{},x:{},c{}:{}}},",
+                i, v.x_val, v.y_index, v.probability
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// The mean time per iteration in nanoseconds of every function in every
+/// group of a suite of benchmarks
+pub struct SuiteMatrix {
+    means: BTreeMap<GroupName, BTreeMap<FnName, Measure>>,
+    baselines: BTreeMap<GroupName, FnName>,
+}
+
+impl Default for SuiteMatrix {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SuiteMatrix {
+    pub fn new() -> SuiteMatrix {
+        SuiteMatrix {
+            means: BTreeMap::<GroupName, BTreeMap<FnName, Measure>>::new(),
+            baselines: BTreeMap::<GroupName, FnName>::new(),
+        }
+    }
+
+    pub fn push(&mut self, group: &str, fn_name: &str, mean_nanos: Measure) {
+        self.means
+            .entry(group.to_ascii_lowercase())
+            .or_default()
+            .insert(fn_name.to_ascii_lowercase(), mean_nanos);
+    }
+
+    pub fn set_baseline(&mut self, group: &str, fn_name: &str) {
+        self.baselines
+            .insert(group.to_ascii_lowercase(), fn_name.to_ascii_lowercase());
+    }
+
+    /// The mean time per iteration in nanoseconds of a function in a group
+    pub fn mean(&self, group: &str, fn_name: &str) -> Option<Measure> {
+        self.means
+            .get(&group.to_ascii_lowercase())?
+            .get(&fn_name.to_ascii_lowercase())
+            .copied()
+    }
+
+    pub fn baseline(&self, group: &str) -> Option<&FnName> {
+        self.baselines.get(&group.to_ascii_lowercase())
+    }
+
+    pub fn functions(&self) -> BTreeSet<&FnName> {
+        self.means
+            .values()
+            .flat_map(|fn_map| fn_map.keys())
+            .collect()
+    }
+}
+
+impl ToTsxFile for SuiteMatrix {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        let functions = self.functions();
+        writeln!(tsxfile, "const SUITE_MATRIX: MatrixInfo = {{")?;
+        write!(tsxfile, "    groups: [")?;
+        for group in self.means.keys() {
+            write!(tsxfile, "{:?},", group)?;
+        }
+        writeln!(tsxfile, "],")?;
+        write!(tsxfile, "    functions: [")?;
+        for fn_name in &functions {
+            write!(tsxfile, "{:?},", fn_name)?;
+        }
+        writeln!(tsxfile, "],")?;
+        writeln!(tsxfile, "    means: [")?;
+        for fn_map in self.means.values() {
+            write!(tsxfile, "        [")?;
+            for fn_name in &functions {
+                match fn_map.get(*fn_name) {
+                    Some(mean) => write!(tsxfile, "{},", json_number(*mean))?,
+                    None => write!(tsxfile, "null,")?,
+                }
+            }
+            writeln!(tsxfile, "],")?;
+        }
+        writeln!(tsxfile, "    ],")?;
+        write!(tsxfile, "    baselines: [")?;
+        for group in self.means.keys() {
+            match self.baselines.get(group) {
+                Some(baseline) => write!(tsxfile, "{:?},", baseline)?,
+                None => write!(tsxfile, "null,")?,
+            }
+        }
+        writeln!(tsxfile, "],")?;
+        writeln!(tsxfile, "}};")?;
+        Ok(())
+    }
+}
+
+/// The suite-wide geometric mean scores
+pub struct SuiteScores {
+    scores: Vec<SuiteScore>,
+}
+
+impl Default for SuiteScores {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SuiteScores {
+    pub fn new() -> SuiteScores {
+        SuiteScores {
+            scores: Vec::<SuiteScore>::new(),
+        }
+    }
+
+    pub fn push(&mut self, score: SuiteScore) {
+        self.scores.push(score);
+    }
+
+    pub fn scores(&self) -> &Vec<SuiteScore> {
+        &self.scores
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scores.is_empty()
+    }
+}
+
+impl ToTsxFile for SuiteScores {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        writeln!(tsxfile, "export const SUITE_SCORES: ScoreInfo[] = [")?;
+        for score in &self.scores {
+            write!(tsxfile, "    ")?;
+            score.to_tsx_file(tsxfile)?;
+            writeln!(tsxfile, ",")?;
+        }
+        writeln!(tsxfile, "];")?;
+        Ok(())
+    }
+}
+
+/// The per iteration times relative to the baseline of the group keyed by
+/// iteration count
+pub struct RelativeDataSet {
+    data: BTreeMap<IterCount, Vec<(YIndex, f64)>>,
+}
+
+impl Default for RelativeDataSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelativeDataSet {
+    pub fn new() -> RelativeDataSet {
+        RelativeDataSet {
+            data: BTreeMap::<IterCount, Vec<(YIndex, f64)>>::new(),
+        }
+    }
+
+    pub fn push(&mut self, iter_count: IterCount, y_index: YIndex, ratio: f64) {
+        self.data
+            .entry(iter_count)
+            .or_default()
+            .push((y_index, ratio));
+    }
+
+    /// The relative time of every function at an iteration count
+    pub fn get(&self, iter_count: IterCount) -> Option<&Vec<(YIndex, f64)>> {
+        self.data.get(&iter_count)
+    }
+}
+
+impl ToTsxFile for RelativeDataSet {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        for (i, (iter_count, y_values)) in self.data.iter().enumerate() {
+            write!(tsxfile, "        {{i:{},x:{},", i, *iter_count as f64)?;
+            for (y_index, ratio) in y_values {
+                write!(tsxfile, "y{}:{},", y_index, json_number(*ratio))?;
+            }
+            writeln!(tsxfile, "}},")?;
+        }
+        Ok(())
+    }
+}
+
+/// The residuals of the total sample times against the linear regression
+/// trendline keyed by iteration count
+pub struct ResidualDataSet {
+    data: BTreeMap<IterCount, Vec<(YIndex, Measure)>>,
+}
+
+impl Default for ResidualDataSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResidualDataSet {
+    pub fn new() -> ResidualDataSet {
+        ResidualDataSet {
+            data: BTreeMap::<IterCount, Vec<(YIndex, Measure)>>::new(),
+        }
+    }
+
+    pub fn push(&mut self, iter_count: IterCount, y_index: YIndex, residual: Measure) {
+        self.data
+            .entry(iter_count)
+            .or_default()
+            .push((y_index, residual));
+    }
+
+    /// The residual of every function at an iteration count
+    pub fn get(&self, iter_count: IterCount) -> Option<&Vec<(YIndex, Measure)>> {
+        self.data.get(&iter_count)
+    }
+}
+
+impl ToTsxFile for ResidualDataSet {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        for (i, (iter_count, y_values)) in self.data.iter().enumerate() {
+            write!(tsxfile, "        {{i:{},x:{},", i, *iter_count as f64)?;
+            for (y_index, residual) in y_values {
+                write!(tsxfile, "e{}:{},", y_index, residual)?;
+            }
+            writeln!(tsxfile, "}},")?;
+        }
+        Ok(())
+    }
+}
+
+struct QqDataPoint {
+    theoretical: f64,
+    y_index: YIndex,
+    sample: Measure,
+    reference: Measure,
+}
+
+/// The sample quantiles of the mean time per iteration of every function
+/// against the quantiles of the standard normal distribution, along with the
+/// reference line of a normal distribution with the sample's mean and
+/// standard deviation.
+pub struct QqDataSet {
+    data: Vec<QqDataPoint>,
+}
+
+impl Default for QqDataSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QqDataSet {
+    pub fn new() -> QqDataSet {
+        QqDataSet {
+            data: Vec::<QqDataPoint>::new(),
+        }
+    }
+
+    pub fn add_qq(
+        &mut self,
+        qq: &[(f64, Measure)],
+        mean: Measure,
+        std_dev: Measure,
+        y_index: YIndex,
+    ) {
+        for (theoretical, sample) in qq {
+            self.data.push(QqDataPoint {
+                theoretical: *theoretical,
+                y_index,
+                sample: *sample,
+                reference: mean + std_dev * theoretical,
+            });
+        }
+    }
+
+    pub fn sort(&mut self) {
+        self.data
+            .sort_by(|a, b| a.theoretical.partial_cmp(&b.theoretical).unwrap());
+    }
+}
+
+impl ToTsxFile for QqDataSet {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        for (i, v) in self.data.iter().enumerate() {
+            writeln!(
+                tsxfile,
+                "        {{i:{},x:{},q{}:{},qr{}:{}}},",
+                i, v.theoretical, v.y_index, v.sample, v.y_index, v.reference
+            )?;
+        }
+        Ok(())
+    }
+}
+
+struct ComplexityDataPoint {
+    param: Param,
+    mean_time: Measure,
+    fitted: Measure,
+    y_index: YIndex,
+}
+
+/// The mean time per iteration of each function against its parameter along
+/// with the best fitting complexity curve.
+pub struct ComplexityData {
+    data: BTreeMap<GroupName, Vec<ComplexityDataPoint>>,
+}
+
+impl Default for ComplexityData {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ComplexityData {
+    pub fn new() -> ComplexityData {
+        ComplexityData {
+            data: BTreeMap::<GroupName, Vec<ComplexityDataPoint>>::new(),
+        }
+    }
+
+    pub fn push(
+        &mut self,
+        group: &str,
+        param_means: &[(Param, Measure)],
+        fit: &ComplexityFit,
+        y_index: YIndex,
+    ) {
+        let points = self.data.entry(group.to_ascii_lowercase()).or_default();
+        for (param, mean_time) in param_means {
+            points.push(ComplexityDataPoint {
+                param: *param,
+                mean_time: *mean_time,
+                fitted: fit.estimate(*param),
+                y_index,
+            });
+        }
+        points.sort_by(|a, b| a.param.partial_cmp(&b.param).unwrap());
+    }
+}
+
+impl ToTsxFile for ComplexityData {
+    fn to_tsx_file(&self, tsxfile: &mut File) -> Result<(), Box<dyn Error>> {
+        writeln!(
+            tsxfile,
+            "const COMPLEXITY_DATA_MAP: Map<string, DataPoint[]> = new Map<string, DataPoint[]>(["
+        )?;
+        for (group, points) in &self.data {
+            writeln!(tsxfile, "    [\"{}\", [", group)?;
+            for (i, p) in points.iter().enumerate() {
+                writeln!(
+                    tsxfile,
+                    "        {{i:{},x:{},y{}:{},f{}:{}}},",
+                    i, p.param, p.y_index, p.mean_time, p.y_index, p.fitted
+                )?;
+            }
+            writeln!(tsxfile, "    ]],")?;
+        }
+        writeln!(tsxfile, "]);")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+use crate::chart::{Chart, ChartKind, Mark};
+use crate::json::{json_number, json_string};
+
+use std::error::Error;
+use std::fmt::Write as FmtWrite;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+const VEGA_LITE_SCHEMA: &str = "https://vega.github.io/schema/vega-lite/v5.json";
+const VEGA_LITE_WIDTH: u32 = 640;
+const VEGA_LITE_HEIGHT: u32 = 400;
+
+/// A Vega-Lite specification of a chart with its data inlined. Every layer
+/// and rule of the chart is a layer of the specification and the functions
+/// are colored with the colors of the chart.
+pub struct VegaLiteSpec<'a> {
+    chart: &'a Chart,
+}
+
+impl<'a> VegaLiteSpec<'a> {
+    pub fn new(chart: &'a Chart) -> VegaLiteSpec<'a> {
+        VegaLiteSpec { chart }
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        json.push_str("{\n");
+        let _ = writeln!(json, "  \"$schema\": {},", json_string(VEGA_LITE_SCHEMA));
+        let _ = writeln!(json, "  \"title\": {},", json_string(&self.chart.title()));
+        let _ = writeln!(json, "  \"width\": {},", VEGA_LITE_WIDTH);
+        let _ = writeln!(json, "  \"height\": {},", VEGA_LITE_HEIGHT);
+        json.push_str("  \"layer\": [");
+
+        let mut layers = Vec::<String>::new();
+        for layer in self.chart.layers() {
+            let values: Vec<String> = layer
+                .points()
+                .iter()
+                .map(|(x, y)| {
+                    format!(
+                        "{{\"x\": {}, \"y\": {}, \"function\": {}}}",
+                        json_number(*x),
+                        json_number(*y),
+                        json_string(layer.fn_name())
+                    )
+                })
+                .collect();
+            layers.push(format!(
+                "{{\"name\": {}, \"data\": {{\"values\": [{}]}}, \"mark\": {}, \"encoding\": {{\"x\": {}, \"y\": {}, \"color\": {}}}}}",
+                json_string(&layer_name(layer.name())),
+                values.join(", "),
+                mark(layer.mark()),
+                self.x_encoding(),
+                self.y_encoding(),
+                self.color_encoding()
+            ));
+        }
+        for rule in self.chart.rules() {
+            layers.push(format!(
+                "{{\"name\": {}, \"data\": {{\"values\": [{{\"x\": {}, \"function\": {}}}]}}, \"mark\": {{\"type\": \"rule\", \"strokeDash\": [4, 4]}}, \"encoding\": {{\"x\": {}, \"color\": {}}}}}",
+                json_string(&layer_name(rule.name())),
+                json_number(rule.x()),
+                json_string(rule.fn_name()),
+                self.x_encoding(),
+                self.color_encoding()
+            ));
+        }
+        for (i, layer) in layers.iter().enumerate() {
+            json.push_str(if i == 0 { "\n    " } else { ",\n    " });
+            json.push_str(layer);
+        }
+        json.push_str("\n  ]\n}\n");
+        json
+    }
+
+    pub fn write(&self, file_path: &Path) -> Result<(), Box<dyn Error>> {
+        let mut jsonfile = File::create(file_path)?;
+        write!(jsonfile, "{}", self.to_json())?;
+        Ok(())
+    }
+
+    fn x_encoding(&self) -> String {
+        format!(
+            "{{\"field\": \"x\", \"type\": \"quantitative\", \"title\": {}, \"scale\": {{\"zero\": false}}}}",
+            json_string(&self.chart.x_label())
+        )
+    }
+
+    /// Only the densities of the PDF chart start at zero
+    fn y_encoding(&self) -> String {
+        format!(
+            "{{\"field\": \"y\", \"type\": \"quantitative\", \"title\": {}, \"scale\": {{\"zero\": {}}}}}",
+            json_string(&self.chart.y_label()),
+            self.chart.kind() == ChartKind::Pdf
+        )
+    }
+
+    fn color_encoding(&self) -> String {
+        let (domain, range): (Vec<String>, Vec<String>) = self
+            .chart
+            .legend()
+            .iter()
+            .map(|(fn_name, color)| (json_string(fn_name), json_string(color)))
+            .unzip();
+        format!(
+            "{{\"field\": \"function\", \"type\": \"nominal\", \"title\": \"Function\", \"scale\": {{\"domain\": [{}], \"range\": [{}]}}}}",
+            domain.join(", "),
+            range.join(", ")
+        )
+    }
+}
+
+fn mark(mark: Mark) -> &'static str {
+    match mark {
+        Mark::Points => "{\"type\": \"point\", \"filled\": true, \"size\": 12}",
+        Mark::Line => "{\"type\": \"line\", \"strokeWidth\": 1}",
+        Mark::Area => "{\"type\": \"area\", \"opacity\": 0.3, \"line\": true}",
+        Mark::StepArea => {
+            "{\"type\": \"area\", \"interpolate\": \"step-after\", \"opacity\": 0.3, \"line\": true}"
+        }
+    }
+}
+
+/// Vega-Lite names may only contain letters, digits and underscores
+fn layer_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::chart::ChartStyle;
+    use crate::rawdata::CriterionData;
+    use crate::timeunit::TimeUnit;
+    use crate::tsxdata::TsxData;
+
+    #[test]
+    fn test_vega_lite_spec() {
+        let mut cdata = CriterionData::new();
+        for (i, measurement) in [10.0, 20.5, 29.5, 40.0].iter().enumerate() {
+            cdata.insert(
+                "Push".to_string(),
+                "Vec".to_string(),
+                *measurement,
+                (i + 1) as u32,
+                TimeUnit::NS,
+                None,
+            );
+        }
+        let mt_data = cdata.mean_time_data(None).unwrap();
+        let dataset = mt_data.get("push").unwrap();
+        let pdf_data = mt_data.data().pdf_data("push").unwrap();
+        let chart = Chart::pdf("push", dataset, &pdf_data, &ChartStyle::new());
+
+        let json = VegaLiteSpec::new(&chart).to_json();
+        assert!(json.starts_with(
+            "{\n  \"$schema\": \"https://vega.github.io/schema/vega-lite/v5.json\",\n  \"title\": \"push: Probability Density\",\n"
+        ));
+        assert!(json.contains("{\"name\": \"Density__vec_\", \"data\": {\"values\": [{\"x\": "));
+        assert!(json.contains("\"mark\": {\"type\": \"area\", \"opacity\": 0.3, \"line\": true}"));
+        assert!(json.contains(
+            "\"mark\": {\"type\": \"rule\", \"strokeDash\": [4, 4]}, \"encoding\": {\"x\": {\"field\": \"x\", \"type\": \"quantitative\", \"title\": \"Mean Time Per Iteration (ns)\""
+        ));
+        assert!(json.contains("\"title\": \"Density\", \"scale\": {\"zero\": true}"));
+        assert!(json.contains("\"scale\": {\"domain\": [\"vec\"], \"range\": [\"#1f77b4\"]}"));
+        assert!(json.ends_with("\n  ]\n}\n"));
+    }
+}